@@ -0,0 +1,151 @@
+use crate::{alternate_print_cards, analyse_outs, Action, ActionError, Chip, PlayerView};
+use std::collections::VecDeque;
+use std::io;
+
+/// Makes the decisions for a seat, a person at a terminal, a bot or a script.
+pub trait PlayerAgent {
+    /// Picks an action for the seat. `legal_actions` has the smallest legal amount for bets
+    /// and raises, larger amounts up to the seat's stack are fine too.
+    fn act(&mut self, view: &PlayerView, legal_actions: &[Action]) -> Action;
+
+    /// Called when the last action from [`PlayerAgent::act`] wasn't legal, before being asked
+    /// again.
+    fn rejected(&mut self, _action: Action, _error: &ActionError) {}
+
+    /// True once the agent won't be making any more decisions, the game stops after the
+    /// current hand.
+    fn has_left(&self) -> bool {
+        false
+    }
+}
+
+/// Prints what the hand is drawing to on the flop and turn, nothing otherwise.
+fn print_outs(view: &PlayerView) {
+    let analysis = match analyse_outs(&view.hole_cards, &view.board) {
+        Ok(analysis) => analysis,
+        Err(_) => return,
+    };
+
+    println!("hand: {:?}", analysis.current);
+    if !analysis.draws.is_empty() {
+        let draws: Vec<String> = analysis.draws.iter().map(|d| d.to_string()).collect();
+        println!("draws: {}", draws.join(", "));
+    }
+    for outs in analysis.outs.iter().filter(|outs| !outs.cards.is_empty()) {
+        println!(
+            "{} outs to {:?}, {:.1}% by the river",
+            outs.cards.len(),
+            outs.rank,
+            outs.by_river_percent
+        );
+    }
+    println!(
+        "improves by the river {:.1}%",
+        analysis.improve_by_river_percent
+    );
+}
+
+/// Asks whoever is at the terminal what to do, reading moves like "c" or "r 20" from stdin.
+/// Once stdin is closed every decision is a fold and the agent leaves the game.
+#[derive(Debug, Default)]
+pub struct TerminalAgent {
+    input_closed: bool,
+}
+
+impl TerminalAgent {
+    pub fn new() -> TerminalAgent {
+        TerminalAgent::default()
+    }
+}
+
+impl PlayerAgent for TerminalAgent {
+    fn act(&mut self, view: &PlayerView, legal_actions: &[Action]) -> Action {
+        println!("player {}", view.seat + 1);
+        alternate_print_cards(&view.hole_cards);
+        print_outs(view);
+        println!(
+            "chips: {} {:?}",
+            view.stack(),
+            Chip::breakdown(view.stack())
+        );
+        println!("bet: {} / to call: {}", view.bet(), view.to_call);
+        println!("Choose your move:");
+        for action in legal_actions {
+            match action {
+                Action::Fold => println!("- f to fold"),
+                Action::Check => println!("- k to check"),
+                Action::Call => println!("- c to call"),
+                Action::Bet(minimum) => println!("- b <amount> to bet, at least {}", minimum),
+                Action::Raise(minimum) => {
+                    println!("- r <amount> to raise to, at least {}", minimum)
+                }
+                Action::AllIn => println!("- a to go all in"),
+            }
+        }
+
+        // keep asking until the input reads as a move
+        loop {
+            if self.input_closed {
+                return Action::Fold;
+            }
+
+            let mut line = String::new();
+            let read = io::stdin()
+                .read_line(&mut line)
+                .expect("failed to pull guess");
+            if read == 0 {
+                // input has been closed, nobody is left to make a choice
+                self.input_closed = true;
+                return Action::Fold;
+            }
+            println!("input: {}", line);
+
+            match line.parse() {
+                Ok(action) => return action,
+                Err(msg) => println!("{}", msg),
+            }
+        }
+    }
+
+    fn rejected(&mut self, _action: Action, error: &ActionError) {
+        println!("{}", error);
+    }
+
+    fn has_left(&self) -> bool {
+        self.input_closed
+    }
+}
+
+/// Plays a fixed list of actions in order, for tests. Once the script runs out it checks when
+/// it can and folds otherwise, and counts as having left the game.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedAgent {
+    actions: VecDeque<Action>,
+}
+
+impl ScriptedAgent {
+    pub fn new(actions: Vec<Action>) -> ScriptedAgent {
+        ScriptedAgent {
+            actions: actions.into(),
+        }
+    }
+
+    /// Actions still to be played.
+    pub fn remaining(&self) -> usize {
+        self.actions.len()
+    }
+}
+
+impl PlayerAgent for ScriptedAgent {
+    fn act(&mut self, _view: &PlayerView, legal_actions: &[Action]) -> Action {
+        match self.actions.pop_front() {
+            Some(action) => action,
+            None if legal_actions.contains(&Action::Check) => Action::Check,
+            None => Action::Fold,
+        }
+    }
+
+    fn has_left(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
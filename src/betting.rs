@@ -0,0 +1,367 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Something a player can do when it's their turn. Amounts are in chips; `Bet` is the size of
+/// the opening bet and `Raise` is the total the player is raising to this street, not the size
+/// of the increase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Bet(u32),
+    Raise(u32),
+    AllIn,
+}
+
+/// Why an action can't be taken right now.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionError {
+    /// The seat has folded or is already all in.
+    CannotAct,
+    /// There's a bet to call so checking isn't possible.
+    CannotCheck { to_call: u32 },
+    /// There's nothing to call, check instead.
+    NothingToCall,
+    /// Someone has already bet this street, raise instead.
+    AlreadyBet,
+    /// Nobody has bet this street, bet instead.
+    NothingToRaise,
+    /// Bets must be at least the big blind and raises must be by at least the last raise,
+    /// unless the player is going all in.
+    BelowMinimum { minimum: u32 },
+    /// The player doesn't have enough chips.
+    NotEnoughChips { stack: u32 },
+    /// The player has acted and the only raise since was a short all in, which doesn't give
+    /// them the chance to raise again.
+    RaiseNotAllowed,
+    /// Everyone else has folded or is all in, so there's nobody to bet against.
+    NoOpponentCanAct,
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ActionError::CannotAct => write!(f, "player has folded or is all in"),
+            ActionError::CannotCheck { to_call } => {
+                write!(f, "can't check, there is {} to call", to_call)
+            }
+            ActionError::NothingToCall => write!(f, "nothing to call, check instead"),
+            ActionError::AlreadyBet => write!(f, "there is already a bet, raise instead"),
+            ActionError::NothingToRaise => write!(f, "there is no bet to raise, bet instead"),
+            ActionError::BelowMinimum { minimum } => {
+                write!(f, "must be at least {} unless going all in", minimum)
+            }
+            ActionError::NotEnoughChips { stack } => {
+                write!(f, "not enough chips, only {} left", stack)
+            }
+            ActionError::RaiseNotAllowed => {
+                write!(f, "betting wasn't reopened, can only call or fold")
+            }
+            ActionError::NoOpponentCanAct => {
+                write!(
+                    f,
+                    "everyone else is all in or has folded, can only call or fold"
+                )
+            }
+        }
+    }
+}
+
+/// Reads an action from what a player typed, e.g. "f", "call", "b 10" or "raise 20".
+impl FromStr for Action {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let action = words.next().ok_or("no action given")?.to_lowercase();
+        let amount = words.next().map(|a| a.parse::<u32>());
+        if words.next().is_some() {
+            return Err("too many words");
+        }
+
+        match (action.as_str(), amount) {
+            ("f" | "fold", None) => Ok(Action::Fold),
+            ("k" | "check", None) => Ok(Action::Check),
+            ("c" | "call", None) => Ok(Action::Call),
+            ("a" | "allin", None) => Ok(Action::AllIn),
+            ("b" | "bet", Some(Ok(amount))) => Ok(Action::Bet(amount)),
+            ("r" | "raise", Some(Ok(amount))) => Ok(Action::Raise(amount)),
+            ("b" | "bet" | "r" | "raise", None) => Err("missing amount"),
+            (_, Some(Err(_))) => Err("amount must be a whole number of chips"),
+            _ => Err("unknown action"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct SeatState {
+    bet: u32,
+    acted: bool,
+    folded: bool,
+    all_in: bool,
+}
+
+/// Tracks one street of betting: how much each seat has put in, the bet to match and the
+/// minimum raise, and who still needs to act. Folds and all ins carry over to the next street
+/// through [`BettingRound::next_street`].
+#[derive(Debug, Clone)]
+pub struct BettingRound {
+    seats: Vec<SeatState>,
+    current_bet: u32,
+    min_raise: u32,
+    big_blind: u32,
+}
+
+impl BettingRound {
+    pub fn new(num_seats: usize, big_blind: u32) -> BettingRound {
+        BettingRound {
+            seats: vec![SeatState::default(); num_seats],
+            current_bet: 0,
+            min_raise: big_blind,
+            big_blind,
+        }
+    }
+
+    /// Starts the next street, keeping who has folded or is all in.
+    pub fn next_street(&self) -> BettingRound {
+        BettingRound {
+            seats: self
+                .seats
+                .iter()
+                .map(|seat| SeatState {
+                    folded: seat.folded,
+                    all_in: seat.all_in,
+                    ..SeatState::default()
+                })
+                .collect(),
+            current_bet: 0,
+            min_raise: self.big_blind,
+            big_blind: self.big_blind,
+        }
+    }
+
+    /// Puts in a forced bet before anyone has acted, returning how many chips went in. The seat
+    /// still gets its turn when the action comes round, and everyone else has to match the full
+    /// blind even if the seat only had enough for part of it.
+    pub fn post_blind(&mut self, seat: usize, amount: u32, stack: u32) -> u32 {
+        let posted = amount.min(stack);
+        self.seats[seat].bet += posted;
+        if posted == stack && posted > 0 {
+            self.seats[seat].all_in = true;
+        }
+        self.current_bet = self.current_bet.max(amount);
+        posted
+    }
+
+    /// Leaves the seat out of the hand, as if it folded before the cards were dealt.
+    pub fn sit_out(&mut self, seat: usize) {
+        self.seats[seat].folded = true;
+    }
+
+    /// The highest amount put in by any seat this street.
+    pub fn current_bet(&self) -> u32 {
+        self.current_bet
+    }
+
+    /// The smallest a raise can increase the current bet by.
+    pub fn min_raise(&self) -> u32 {
+        self.min_raise
+    }
+
+    /// How much the seat has put in this street.
+    pub fn bet(&self, seat: usize) -> u32 {
+        self.seats[seat].bet
+    }
+
+    pub fn to_call(&self, seat: usize) -> u32 {
+        self.current_bet - self.seats[seat].bet
+    }
+
+    pub fn has_folded(&self, seat: usize) -> bool {
+        self.seats[seat].folded
+    }
+
+    pub fn is_all_in(&self, seat: usize) -> bool {
+        self.seats[seat].all_in
+    }
+
+    /// True if the seat still has decisions to make this hand.
+    pub fn can_act(&self, seat: usize) -> bool {
+        !self.seats[seat].folded && !self.seats[seat].all_in
+    }
+
+    /// Number of seats that haven't folded.
+    pub fn players_in_hand(&self) -> usize {
+        self.seats.iter().filter(|seat| !seat.folded).count()
+    }
+
+    /// True if any seat other than this one still has chips to respond to a bet with.
+    pub fn opponent_can_act(&self, seat: usize) -> bool {
+        (0..self.seats.len()).any(|other| other != seat && self.can_act(other))
+    }
+
+    /// True if the seat can act and hasn't yet matched the bet or had a chance to respond to
+    /// the last raise.
+    pub fn needs_to_act(&self, seat: usize) -> bool {
+        let state = &self.seats[seat];
+        self.can_act(seat) && (!state.acted || state.bet < self.current_bet)
+    }
+
+    /// The street is over once everyone left has acted and matched the highest bet, or when
+    /// only one player hasn't folded.
+    pub fn is_complete(&self) -> bool {
+        if self.players_in_hand() <= 1 {
+            return true;
+        }
+
+        // nothing to decide if at most one player has chips left to bet with and they've
+        // nothing to call, even the big blind's option
+        let can_act: Vec<usize> = (0..self.seats.len()).filter(|s| self.can_act(*s)).collect();
+        if can_act.len() <= 1 && can_act.iter().all(|seat| self.to_call(*seat) == 0) {
+            return true;
+        }
+
+        (0..self.seats.len()).all(|seat| !self.needs_to_act(seat))
+    }
+
+    /// The next seat to act going round the table from the seat after `after`, or `None` once
+    /// the street is complete. Start from the big blind preflop and the button after that.
+    pub fn next_to_act(&self, after: usize) -> Option<usize> {
+        if self.is_complete() {
+            return None;
+        }
+
+        let num_seats = self.seats.len();
+        (1..=num_seats)
+            .map(|n| (after + n) % num_seats)
+            .find(|seat| self.needs_to_act(*seat))
+    }
+
+    /// The actions the seat can take, with the smallest legal amount for bets and raises.
+    pub fn legal_actions(&self, seat: usize, stack: u32) -> Vec<Action> {
+        if !self.can_act(seat) {
+            return vec![];
+        }
+
+        let candidates = [
+            Action::Fold,
+            Action::Check,
+            Action::Call,
+            Action::Bet(self.big_blind),
+            Action::Raise(self.current_bet + self.min_raise),
+            Action::AllIn,
+        ];
+
+        candidates
+            .into_iter()
+            .filter(|action| self.validate(seat, *action, stack).is_ok())
+            .collect()
+    }
+
+    /// Checks the action is legal and returns how many chips it would put in.
+    pub fn validate(&self, seat: usize, action: Action, stack: u32) -> Result<u32, ActionError> {
+        if !self.can_act(seat) {
+            return Err(ActionError::CannotAct);
+        }
+
+        let state = &self.seats[seat];
+        let to_call = self.current_bet - state.bet;
+        match action {
+            Action::Fold => Ok(0),
+            Action::Check => {
+                if to_call > 0 {
+                    return Err(ActionError::CannotCheck { to_call });
+                }
+                Ok(0)
+            }
+            Action::Call => {
+                if to_call == 0 {
+                    return Err(ActionError::NothingToCall);
+                }
+                // calling for less than the full amount puts the player all in
+                Ok(to_call.min(stack))
+            }
+            Action::Bet(amount) => {
+                if self.current_bet > 0 {
+                    return Err(ActionError::AlreadyBet);
+                }
+                if !self.opponent_can_act(seat) {
+                    return Err(ActionError::NoOpponentCanAct);
+                }
+                if amount > stack {
+                    return Err(ActionError::NotEnoughChips { stack });
+                }
+                if amount < self.big_blind && amount < stack {
+                    return Err(ActionError::BelowMinimum {
+                        minimum: self.big_blind,
+                    });
+                }
+                Ok(amount)
+            }
+            Action::Raise(to) => {
+                if self.current_bet == 0 {
+                    return Err(ActionError::NothingToRaise);
+                }
+                if !self.opponent_can_act(seat) {
+                    return Err(ActionError::NoOpponentCanAct);
+                }
+                let amount = to.saturating_sub(state.bet);
+                if amount > stack {
+                    return Err(ActionError::NotEnoughChips { stack });
+                }
+                let minimum = self.current_bet + self.min_raise;
+                if to <= self.current_bet || (to < minimum && amount < stack) {
+                    return Err(ActionError::BelowMinimum { minimum });
+                }
+                if state.acted {
+                    return Err(ActionError::RaiseNotAllowed);
+                }
+                Ok(amount)
+            }
+            Action::AllIn => {
+                if stack > to_call && !self.opponent_can_act(seat) {
+                    return Err(ActionError::NoOpponentCanAct);
+                }
+                if stack > to_call && state.acted {
+                    return Err(ActionError::RaiseNotAllowed);
+                }
+                Ok(stack)
+            }
+        }
+    }
+
+    /// Takes the action for the seat, returning how many chips it put in. `stack` is what the
+    /// seat had before acting.
+    pub fn apply(&mut self, seat: usize, action: Action, stack: u32) -> Result<u32, ActionError> {
+        let amount = self.validate(seat, action, stack)?;
+
+        if action == Action::Fold {
+            self.seats[seat].folded = true;
+            return Ok(0);
+        }
+
+        self.seats[seat].bet += amount;
+        self.seats[seat].acted = true;
+        if amount == stack && amount > 0 {
+            self.seats[seat].all_in = true;
+        }
+
+        let bet = self.seats[seat].bet;
+        if bet > self.current_bet {
+            let raise = bet - self.current_bet;
+            self.current_bet = bet;
+            // only a full raise reopens the betting for players who have already acted
+            if raise >= self.min_raise {
+                self.min_raise = raise;
+                for (i, other) in self.seats.iter_mut().enumerate() {
+                    if i != seat {
+                        other.acted = false;
+                    }
+                }
+            }
+        }
+
+        Ok(amount)
+    }
+}
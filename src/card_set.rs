@@ -0,0 +1,280 @@
+use crate::{Card, PackedCard, Rank, Suit};
+use std::error::Error;
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+use strum::IntoEnumIterator;
+
+const ALL_CARDS: u64 = (1 << PackedCard::COUNT) - 1;
+
+/// Why a card couldn't be put into play, see [`CardSet::claim`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardError {
+    /// The same card is in two places at once, like a hand and the board.
+    DuplicateCard { card: Card },
+    /// The card isn't one of the 52 in a deck.
+    InvalidCard { card: Card },
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardError::DuplicateCard { card } => write!(f, "{:#} is used more than once", card),
+            CardError::InvalidCard { card } => write!(f, "{:?} isn't a card in the deck", card),
+        }
+    }
+}
+
+impl Error for CardError {}
+
+/// A set of cards from a single deck, one bit per card at its [`PackedCard::index`]. Hands,
+/// boards and dead cards can be combined and compared with the usual set operators:
+///
+/// * `a | b` union
+/// * `a & b` intersection
+/// * `a - b` difference
+/// * `!a` every card in the deck not in `a`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    pub fn new() -> CardSet {
+        CardSet(0)
+    }
+
+    /// All 52 cards in the deck.
+    pub fn full() -> CardSet {
+        CardSet(ALL_CARDS)
+    }
+
+    /// Builds a set from raw bits, anything above the 52nd bit is dropped.
+    pub fn from_bits(bits: u64) -> CardSet {
+        CardSet(bits & ALL_CARDS)
+    }
+
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Every card of the given suit.
+    pub fn suit(suit: Suit) -> CardSet {
+        Rank::iter()
+            .map(|rank| PackedCard::new(rank, suit))
+            .collect()
+    }
+
+    /// All four cards of the given rank.
+    pub fn rank(rank: Rank) -> CardSet {
+        CardSet(0b1111 << (rank.index() * 4))
+    }
+
+    /// Adds a card, returning false if it was already in the set.
+    pub fn insert(&mut self, card: PackedCard) -> bool {
+        let missing = !self.contains(card);
+        self.0 |= card.bit();
+        missing
+    }
+
+    /// Adds a card that's being put into play somewhere, erroring if it's already in play or
+    /// isn't a card from the deck.
+    pub fn claim(&mut self, card: &Card) -> Result<PackedCard, CardError> {
+        let packed = PackedCard::try_from(card)
+            .map_err(|_| CardError::InvalidCard { card: card.clone() })?;
+        if !self.insert(packed) {
+            return Err(CardError::DuplicateCard { card: card.clone() });
+        }
+        Ok(packed)
+    }
+
+    /// Removes a card, returning false if it wasn't in the set.
+    pub fn remove(&mut self, card: PackedCard) -> bool {
+        let present = self.contains(card);
+        self.0 &= !card.bit();
+        present
+    }
+
+    pub fn contains(self, card: PackedCard) -> bool {
+        self.0 & card.bit() != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    pub fn is_disjoint(self, other: CardSet) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    pub fn is_subset(self, other: CardSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// The ranks held in the given suit as 13 bits, bit 0 for a Two up to bit 12 for an Ace.
+    pub fn suit_mask(self, suit: Suit) -> u16 {
+        self.iter()
+            .filter(|card| card.suit() == suit)
+            .fold(0, |mask, card| mask | 1 << card.rank_index())
+    }
+
+    /// The ranks held in any suit as 13 bits, bit 0 for a Two up to bit 12 for an Ace.
+    pub fn rank_mask(self) -> u16 {
+        Suit::iter().fold(0, |mask, suit| mask | self.suit_mask(suit))
+    }
+
+    /// How many cards of the given rank are in the set.
+    pub fn rank_count(self, rank: Rank) -> usize {
+        self.intersection(CardSet::rank(rank)).len()
+    }
+
+    /// The suit with five or more cards in the set, if there is one.
+    pub fn flush_suit(self) -> Option<Suit> {
+        Suit::iter().find(|suit| self.intersection(CardSet::suit(*suit)).len() >= 5)
+    }
+
+    /// Iterates over the cards in index order, lowest rank first.
+    pub fn iter(self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    pub fn to_cards(self) -> Vec<Card> {
+        self.iter().map(Card::from).collect()
+    }
+}
+
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = PackedCard;
+
+    fn next(&mut self) -> Option<PackedCard> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Some(PackedCard::try_from(index).expect("set only holds valid cards"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = PackedCard;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<PackedCard> for CardSet {
+    fn from_iter<I: IntoIterator<Item = PackedCard>>(iter: I) -> Self {
+        CardSet(iter.into_iter().fold(0, |bits, card| bits | card.bit()))
+    }
+}
+
+impl From<PackedCard> for CardSet {
+    fn from(card: PackedCard) -> Self {
+        CardSet(card.bit())
+    }
+}
+
+impl TryFrom<&[Card]> for CardSet {
+    type Error = &'static str;
+
+    fn try_from(cards: &[Card]) -> Result<Self, Self::Error> {
+        cards.iter().map(PackedCard::try_from).collect()
+    }
+}
+
+impl TryFrom<&Vec<Card>> for CardSet {
+    type Error = &'static str;
+
+    fn try_from(cards: &Vec<Card>) -> Result<Self, Self::Error> {
+        CardSet::try_from(cards.as_slice())
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, other: CardSet) {
+        *self = self.union(other);
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl BitAndAssign for CardSet {
+    fn bitand_assign(&mut self, other: CardSet) {
+        *self = self.intersection(other);
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl SubAssign for CardSet {
+    fn sub_assign(&mut self, other: CardSet) {
+        *self = self.difference(other);
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    fn not(self) -> CardSet {
+        CardSet::full().difference(self)
+    }
+}
+
+impl fmt::Display for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cards: Vec<String> = self
+            .iter()
+            .map(|card| {
+                if f.alternate() {
+                    format!("{:#}", card)
+                } else {
+                    card.to_string()
+                }
+            })
+            .collect();
+        write!(f, "{}", cards.join(" "))
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    best_hand, print_cards, print_table_cards, Action, Blind, Card, GameEvent, GameObserver, Round,
+};
+use std::collections::HashMap;
+
+/// Prints what's happening at the table to stdout. Hole cards are only printed once they're
+/// shown at the end of a hand.
+#[derive(Debug, Default)]
+pub struct ConsoleObserver {
+    pot: u32,
+    button: usize,
+    board: Vec<Card>,
+    shown: HashMap<usize, Vec<Card>>,
+    pots_awarded: usize,
+}
+
+impl ConsoleObserver {
+    pub fn new() -> ConsoleObserver {
+        ConsoleObserver::default()
+    }
+
+    fn print_round(&self, round: Round) {
+        let name = match round {
+            Round::PreFlop => "pre flop",
+            Round::Flop => "flop",
+            Round::Turn => "turn",
+            Round::River => "river",
+        };
+        println!("ROUND::{}", name);
+        println!("---------------");
+        println!("pot... {}", self.pot);
+        print_table_cards(&self.board);
+        println!("dealer indicator... {:?}", self.button);
+        println!();
+    }
+}
+
+impl GameObserver for ConsoleObserver {
+    fn observe(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::HandStarted {
+                hand,
+                seed,
+                positions,
+            } => {
+                *self = ConsoleObserver {
+                    button: positions.button,
+                    ..ConsoleObserver::default()
+                };
+                println!("HAND {} (seed {})", hand, seed);
+                println!("===============");
+            }
+            GameEvent::BlindPosted {
+                seat,
+                blind,
+                amount,
+            } => {
+                self.pot += amount;
+                match blind {
+                    Blind::Small => println!("player {} posts the small blind", seat + 1),
+                    Blind::Big => {
+                        // the big blind always goes in last, then the betting starts
+                        println!("player {} posts the big blind", seat + 1);
+                        self.print_round(Round::PreFlop);
+                    }
+                }
+            }
+            GameEvent::HoleCardsDealt { .. } => {}
+            GameEvent::PlayerActed {
+                seat,
+                action,
+                amount,
+            } => {
+                self.pot += amount;
+                match action {
+                    Action::Fold => println!("player {} folded", seat + 1),
+                    Action::Check => println!("player {} checked", seat + 1),
+                    _ => println!("player {} put in {}", seat + 1, amount),
+                }
+            }
+            GameEvent::BoardDealt { round, cards } => {
+                self.board.extend(cards.iter().cloned());
+                self.print_round(*round);
+            }
+            GameEvent::CardsShown { seat, cards } => {
+                print!("player {} shows ", seat + 1);
+                print_cards(&cards.iter().collect());
+                println!();
+                self.shown.insert(*seat, cards.clone());
+            }
+            GameEvent::PotAwarded(award) => {
+                let name = if self.pots_awarded == 0 {
+                    String::from("main pot")
+                } else {
+                    format!("side pot {}", self.pots_awarded)
+                };
+                self.pots_awarded += 1;
+
+                for (seat, amount) in &award.winners {
+                    match self.shown.get(seat) {
+                        Some(cards) => {
+                            let best = best_hand(cards, &self.board);
+                            print!(
+                                "player {} wins {} from the {} with ",
+                                seat + 1,
+                                amount,
+                                name
+                            );
+                            print_cards(&best.cards.iter().collect());
+                            println!("rank: {:?}", best.value.rank);
+                        }
+                        None => {
+                            println!("player {} wins {}, everyone else folded", seat + 1, amount)
+                        }
+                    }
+                }
+            }
+            GameEvent::HandEnded { .. } => println!(),
+        }
+    }
+}
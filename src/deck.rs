@@ -0,0 +1,127 @@
+use crate::{Card, CardSet, PackedCard, Rank, Suit};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::error::Error;
+use std::fmt;
+use strum::IntoEnumIterator;
+
+/// Asked for more cards than were left in the deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeckExhausted {
+    pub wanted: usize,
+    pub remaining: usize,
+}
+
+impl fmt::Display for DeckExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "wanted {} cards but only {} left in the deck",
+            self.wanted, self.remaining
+        )
+    }
+}
+
+impl Error for DeckExhausted {}
+
+/// The cards that haven't been dealt yet. Cards come off the top of the deck, which is the end
+/// of [`Deck::cards`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+    /// The same cards as `cards`, for checking what's left without a scan.
+    set: CardSet,
+}
+
+impl Deck {
+    /// A full 52 card deck in suit then rank order, the Ace of Clubs on top.
+    pub fn new() -> Deck {
+        let mut cards = Vec::new();
+        for suit in Suit::iter() {
+            for rank in Rank::iter() {
+                cards.push(Card::new(rank, suit))
+            }
+        }
+        Deck {
+            cards,
+            set: CardSet::full(),
+        }
+    }
+
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Takes the top card.
+    pub fn deal(&mut self) -> Result<Card, DeckExhausted> {
+        let card = self.cards.pop().ok_or(DeckExhausted {
+            wanted: 1,
+            remaining: 0,
+        })?;
+        self.set.remove(packed(&card));
+        Ok(card)
+    }
+
+    /// Takes `count` cards off the top, in the order they were dealt. Nothing is taken if there
+    /// aren't enough.
+    pub fn deal_many(&mut self, count: usize) -> Result<Vec<Card>, DeckExhausted> {
+        if count > self.cards.len() {
+            return Err(DeckExhausted {
+                wanted: count,
+                remaining: self.cards.len(),
+            });
+        }
+        Ok((0..count).filter_map(|_| self.deal().ok()).collect())
+    }
+
+    /// Throws away the top card face down, returning it.
+    pub fn burn(&mut self) -> Result<Card, DeckExhausted> {
+        self.deal()
+    }
+
+    /// Takes out cards already known to be somewhere else, like dead cards or a hand being
+    /// analysed, so they can't be dealt. Returns how many were found in the deck.
+    pub fn remove(&mut self, cards: &[Card]) -> usize {
+        let found: CardSet = cards
+            .iter()
+            .filter_map(|card| PackedCard::try_from(card).ok())
+            .filter(|card| self.set.contains(*card))
+            .collect();
+        self.cards.retain(|card| !found.contains(packed(card)));
+        self.set -= found;
+        found.len()
+    }
+
+    pub fn contains(&self, card: &Card) -> bool {
+        matches!(PackedCard::try_from(card), Ok(card) if self.set.contains(card))
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// The cards left, bottom of the deck first.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// The cards left as a set, for checking against hands and boards.
+    pub fn card_set(&self) -> CardSet {
+        self.set
+    }
+}
+
+/// Every card in a deck is one of the 52, so it always packs.
+fn packed(card: &Card) -> PackedCard {
+    PackedCard::new(card.rank(), card.suit)
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
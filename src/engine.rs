@@ -0,0 +1,579 @@
+use crate::{
+    best_hand, Action, ActionError, ActionRecord, BettingRound, Blind, Card, Deck, GameEvent,
+    GameObserver, HandValue, OddChipRule, Perspective, PlayerView, PotManager, Round,
+    TablePositions, STARTING_STACK,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::error::Error;
+use std::fmt;
+
+/// The most seats a table can have, enough cards for everyone's hole cards, a full board and
+/// a burn card before each round.
+pub const MAX_PLAYERS: usize = 22;
+
+/// Why the engine couldn't do what it was asked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineError {
+    /// Fewer than two seats have chips, so there's no hand to play.
+    NotEnoughPlayers,
+    TooManyPlayers {
+        max: usize,
+    },
+    /// The last hand hasn't finished yet.
+    HandInProgress,
+    NoHandInProgress,
+    /// Someone acted out of turn. `expected` is `None` when nobody has a decision to make.
+    NotYourTurn {
+        seat: usize,
+        expected: Option<usize>,
+    },
+    IllegalAction(ActionError),
+    /// There's no seat with that number at the table.
+    NoSuchSeat {
+        seat: usize,
+        seats: usize,
+    },
+    /// A session needs exactly one agent for each seat.
+    WrongNumberOfAgents {
+        agents: usize,
+        seats: usize,
+    },
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EngineError::NotEnoughPlayers => write!(f, "need at least two players with chips"),
+            EngineError::TooManyPlayers { max } => {
+                write!(f, "there can't be more than {} players", max)
+            }
+            EngineError::HandInProgress => write!(f, "a hand is already being played"),
+            EngineError::NoHandInProgress => write!(f, "no hand is being played"),
+            EngineError::NotYourTurn {
+                seat,
+                expected: Some(expected),
+            } => write!(
+                f,
+                "it's player {}'s turn, not player {}'s",
+                expected + 1,
+                seat + 1
+            ),
+            EngineError::NotYourTurn {
+                seat,
+                expected: None,
+            } => write!(f, "nobody can act right now, including player {}", seat + 1),
+            EngineError::IllegalAction(err) => write!(f, "{}", err),
+            EngineError::NoSuchSeat { seat, seats } => write!(
+                f,
+                "there's no player {}, the table only has {} seats",
+                seat + 1,
+                seats
+            ),
+            EngineError::WrongNumberOfAgents { agents, seats } => write!(
+                f,
+                "there are {} agents for {} seats, need one for each seat",
+                agents, seats
+            ),
+        }
+    }
+}
+
+impl Error for EngineError {}
+
+impl From<ActionError> for EngineError {
+    fn from(err: ActionError) -> Self {
+        EngineError::IllegalAction(err)
+    }
+}
+
+#[derive(Debug)]
+struct Player {
+    // could potentially make this an enum
+    cards: Vec<Card>, // this could also be an array of size 5
+    stack: u32,
+}
+
+/// Runs hands of Hold'em one step at a time. Start a hand, ask whose turn it is and what they
+/// can do, apply their action and repeat; the engine deals the board, moves between rounds and
+/// pays out the pots by itself. Everything that happens is sent as a [`GameEvent`] to any
+/// observers and kept to be picked up with [`Engine::drain_events`].
+///
+/// Every hand is shuffled from its own seed, drawn from the engine's random number generator and
+/// sent out with [`GameEvent::HandStarted`]. Passing that seed to
+/// [`Engine::start_hand_with_seed`] deals exactly the same cards again.
+///
+/// ```
+/// use rust_poker::{Action, Engine};
+///
+/// let mut engine = Engine::new(3).unwrap();
+/// engine.start_hand().unwrap();
+/// while let Some(seat) = engine.to_act() {
+///     let action = if engine.legal_actions().contains(&Action::Check) {
+///         Action::Check
+///     } else {
+///         Action::Call
+///     };
+///     engine.apply(seat, action).unwrap();
+/// }
+/// assert!(!engine.hand_in_progress());
+/// assert_eq!(engine.stacks().iter().sum::<u32>(), 300);
+/// ```
+pub struct Engine {
+    deck: Deck,
+    players: Vec<Player>,
+    round: Round,
+    shared_cards: Vec<Card>,
+    /// Where the button and blinds are this hand, `None` until the first hand is started.
+    positions: Option<TablePositions>,
+    small_blind: u32,
+    big_blind: u32,
+    betting: BettingRound,
+    pots: PotManager,
+    odd_chip_rule: OddChipRule,
+    history: Vec<ActionRecord>,
+    hands_played: u32,
+    in_progress: bool,
+    /// The last seat to act, the next turn goes to the first seat after it that can act.
+    last_to_act: usize,
+    events: Vec<GameEvent>,
+    observers: Vec<(Perspective, Box<dyn GameObserver>)>,
+    /// Where each hand's seed comes from.
+    rng: Box<dyn RngCore>,
+    hand_seed: Option<u64>,
+}
+
+/// Observers and the random number generator are only counted or left out, they don't have to
+/// implement `Debug`.
+impl fmt::Debug for Engine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Engine")
+            .field("deck", &self.deck)
+            .field("players", &self.players)
+            .field("round", &self.round)
+            .field("shared_cards", &self.shared_cards)
+            .field("positions", &self.positions)
+            .field("small_blind", &self.small_blind)
+            .field("big_blind", &self.big_blind)
+            .field("betting", &self.betting)
+            .field("pots", &self.pots)
+            .field("odd_chip_rule", &self.odd_chip_rule)
+            .field("history", &self.history)
+            .field("hands_played", &self.hands_played)
+            .field("in_progress", &self.in_progress)
+            .field("last_to_act", &self.last_to_act)
+            .field("events", &self.events)
+            .field("observers", &self.observers.len())
+            .field("hand_seed", &self.hand_seed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Engine {
+    /// A table where every seat starts with [`STARTING_STACK`] chips.
+    pub fn new(num_players: usize) -> Result<Engine, EngineError> {
+        Engine::with_stacks(vec![STARTING_STACK; num_players])
+    }
+
+    /// A table with the given number of chips in front of each seat. Seats without chips sit
+    /// out every hand.
+    pub fn with_stacks(stacks: Vec<u32>) -> Result<Engine, EngineError> {
+        let num_players = stacks.len();
+        // initialize players
+        if stacks.iter().filter(|stack| **stack > 0).count() < 2 {
+            return Err(EngineError::NotEnoughPlayers);
+        }
+        if num_players > MAX_PLAYERS {
+            return Err(EngineError::TooManyPlayers { max: MAX_PLAYERS });
+        }
+
+        Ok(Engine {
+            deck: Deck::new(),
+            players: stacks
+                .into_iter()
+                .map(|stack| Player {
+                    cards: vec![],
+                    stack,
+                })
+                .collect(),
+            round: Round::PreFlop,
+            shared_cards: vec![],
+            positions: None,
+            small_blind: 1,
+            big_blind: 2,
+            betting: BettingRound::new(num_players, 2),
+            pots: PotManager::new(num_players),
+            odd_chip_rule: OddChipRule::default(),
+            history: vec![],
+            hands_played: 0,
+            in_progress: false,
+            last_to_act: 0,
+            events: vec![],
+            observers: vec![],
+            rng: Box::new(StdRng::from_entropy()),
+            hand_seed: None,
+        })
+    }
+
+    pub fn set_odd_chip_rule(&mut self, rule: OddChipRule) {
+        self.odd_chip_rule = rule;
+    }
+
+    /// Uses the given random number generator to pick the seed for each hand from now on.
+    pub fn set_rng<R: Rng + 'static>(&mut self, rng: R) {
+        self.rng = Box::new(rng);
+    }
+
+    /// Seeds the random number generator, so the same seed plays out the same deals.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.set_rng(StdRng::seed_from_u64(seed));
+    }
+
+    /// The seed the current or last hand was shuffled with.
+    pub fn hand_seed(&self) -> Option<u64> {
+        self.hand_seed
+    }
+
+    /// Sends every event from now on to the observer as well, except for hole cards that
+    /// haven't been shown.
+    pub fn add_observer(&mut self, observer: Box<dyn GameObserver>) {
+        self.add_observer_for(Perspective::Table, observer);
+    }
+
+    /// Sends the observer every event from now on that the perspective lets it see.
+    pub fn add_observer_for(&mut self, perspective: Perspective, observer: Box<dyn GameObserver>) {
+        self.observers.push((perspective, observer));
+    }
+
+    pub fn num_seats(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Chips in front of every seat, not counting anything in the pot.
+    pub fn stacks(&self) -> Vec<u32> {
+        self.players.iter().map(|player| player.stack).collect()
+    }
+
+    /// Where the button and blinds are for the current or last hand.
+    pub fn positions(&self) -> Option<TablePositions> {
+        self.positions
+    }
+
+    pub fn round(&self) -> Round {
+        self.round
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.shared_cards
+    }
+
+    /// Everything put in by every player this hand.
+    pub fn pot(&self) -> u32 {
+        self.pots.total()
+    }
+
+    pub fn hands_played(&self) -> u32 {
+        self.hands_played
+    }
+
+    pub fn hand_in_progress(&self) -> bool {
+        self.in_progress
+    }
+
+    /// True once fewer than two seats have chips and no more hands can be played.
+    pub fn is_game_over(&self) -> bool {
+        !self.in_progress && self.active_seats().iter().filter(|a| **a).count() < 2
+    }
+
+    /// Moves the button on, gets a fresh shuffled deck, deals to everyone with chips and posts
+    /// the blinds.
+    pub fn start_hand(&mut self) -> Result<(), EngineError> {
+        if self.in_progress {
+            return Err(EngineError::HandInProgress);
+        }
+        let seed = self.rng.gen();
+        self.start_hand_with_seed(seed)
+    }
+
+    /// Starts a hand like [`Engine::start_hand`] but shuffles with the given seed, to replay a
+    /// hand that was seen before.
+    pub fn start_hand_with_seed(&mut self, seed: u64) -> Result<(), EngineError> {
+        if self.in_progress {
+            return Err(EngineError::HandInProgress);
+        }
+        let active = self.active_seats();
+        let positions = match self.positions {
+            None => TablePositions::first_hand(0, &active),
+            Some(positions) => positions.next_hand(&active),
+        }
+        .ok_or(EngineError::NotEnoughPlayers)?;
+
+        let num_seats = self.players.len();
+        self.positions = Some(positions);
+        self.hands_played += 1;
+        self.in_progress = true;
+        self.hand_seed = Some(seed);
+        self.deck = Deck::new();
+        self.deck.shuffle(&mut StdRng::seed_from_u64(seed));
+        self.round = Round::PreFlop;
+        self.shared_cards.clear();
+        self.history.clear();
+        self.betting = BettingRound::new(num_seats, self.big_blind);
+        self.pots = PotManager::new(num_seats);
+        self.emit(GameEvent::HandStarted {
+            hand: self.hands_played,
+            seed,
+            positions,
+        });
+
+        for (i, active) in active.into_iter().enumerate() {
+            self.players[i].cards.clear();
+            if !active {
+                self.betting.sit_out(i);
+                self.pots.fold(i);
+            }
+        }
+
+        self.deal_cards();
+        self.post_blinds(positions);
+
+        // action starts left of the big blind preflop
+        self.last_to_act = positions.big_blind;
+        self.advance()
+    }
+
+    /// The seat whose turn it is, `None` between hands.
+    pub fn to_act(&self) -> Option<usize> {
+        if !self.in_progress {
+            return None;
+        }
+        self.betting.next_to_act(self.last_to_act)
+    }
+
+    /// What the seat whose turn it is can do, with the smallest legal amount for bets and
+    /// raises. Empty if it's nobody's turn.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        match self.to_act() {
+            Some(seat) => self.betting.legal_actions(seat, self.players[seat].stack),
+            None => vec![],
+        }
+    }
+
+    /// Takes the action for the seat whose turn it is. Once the betting round is over the
+    /// engine deals the next card or cards, or pays out the pots if the hand is finished.
+    pub fn apply(&mut self, seat: usize, action: Action) -> Result<(), EngineError> {
+        if !self.in_progress {
+            return Err(EngineError::NoHandInProgress);
+        }
+        let expected = self.to_act();
+        if expected != Some(seat) {
+            return Err(EngineError::NotYourTurn { seat, expected });
+        }
+
+        let stack = self.players[seat].stack;
+        let amount = self.betting.apply(seat, action, stack)?;
+        self.commit(seat, amount);
+        if action == Action::Fold {
+            self.pots.fold(seat);
+        }
+        self.history.push(ActionRecord {
+            seat,
+            round: self.round,
+            action,
+            amount,
+        });
+        self.emit(GameEvent::PlayerActed {
+            seat,
+            action,
+            amount,
+        });
+
+        self.last_to_act = seat;
+        self.advance()
+    }
+
+    /// Takes every event since the last call, oldest first. This is everything, every seat's
+    /// hole cards included.
+    pub fn drain_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// What the seat gets to see, its own cards and nobody else's. Between hands it's what was
+    /// left at the end of the last one; before the first hand there's nothing to see.
+    pub fn view(&self, seat: usize) -> Result<PlayerView, EngineError> {
+        let num_seats = self.players.len();
+        if seat >= num_seats {
+            return Err(EngineError::NoSuchSeat {
+                seat,
+                seats: num_seats,
+            });
+        }
+        let positions = self.hand_positions()?;
+        Ok(PlayerView {
+            seat,
+            hole_cards: self.players[seat].cards.clone(),
+            board: self.shared_cards.clone(),
+            round: self.round,
+            positions,
+            stacks: self.stacks(),
+            bets: (0..num_seats).map(|i| self.betting.bet(i)).collect(),
+            folded: (0..num_seats).map(|i| self.betting.has_folded(i)).collect(),
+            to_call: self.betting.to_call(seat),
+            pot: self.pot(),
+            history: self.history.clone(),
+        })
+    }
+
+    /// Where the button and blinds are for the hand being played, or the last one.
+    fn hand_positions(&self) -> Result<TablePositions, EngineError> {
+        self.positions.ok_or(EngineError::NoHandInProgress)
+    }
+
+    /// Moves the hand on until somebody has a decision to make or it's over.
+    fn advance(&mut self) -> Result<(), EngineError> {
+        loop {
+            if self.betting.players_in_hand() <= 1 {
+                return self.finish_hand();
+            }
+            if self.to_act().is_some() {
+                return Ok(());
+            }
+
+            let (next, cards) = match self.round {
+                Round::PreFlop => (Round::Flop, 3),
+                Round::Flop => (Round::Turn, 1),
+                Round::Turn => (Round::River, 1),
+                Round::River => return self.finish_hand(),
+            };
+
+            // burn one before turning over the next cards
+            self.deck.burn().expect("there's a card for every burn");
+            let dealt = self
+                .deck
+                .deal_many(cards)
+                .expect("there's a card for the whole board");
+            self.shared_cards.extend(dealt.iter().cloned());
+            self.round = next;
+            self.betting = self.betting.next_street();
+            self.emit(GameEvent::BoardDealt {
+                round: next,
+                cards: dealt,
+            });
+
+            // action starts left of the button after the flop
+            self.last_to_act = self.hand_positions()?.button;
+        }
+    }
+
+    /// Shows down the hands still in and pays out every pot.
+    fn finish_hand(&mut self) -> Result<(), EngineError> {
+        // only the players still in at the end show their cards
+        if self.betting.players_in_hand() > 1 {
+            for seat in 0..self.players.len() {
+                if !self.betting.has_folded(seat) {
+                    let cards = self.players[seat].cards.clone();
+                    self.emit(GameEvent::CardsShown { seat, cards });
+                }
+            }
+        }
+
+        // figure out who won
+        let button = self.hand_positions()?.button;
+        let hole_cards: Vec<Vec<Card>> = self.players.iter().map(|p| p.cards.clone()).collect();
+        let odd_chip_order = self.odd_chip_rule.seat_order(button, &hole_cards);
+        let awards = self.pots.award(&self.hand_values(), &odd_chip_order);
+        for (player, amount) in self.pots.payouts(&awards).into_iter().enumerate() {
+            self.players[player].stack += amount;
+        }
+
+        for award in awards {
+            self.emit(GameEvent::PotAwarded(award));
+        }
+        self.emit(GameEvent::HandEnded {
+            stacks: self.stacks(),
+        });
+        self.pots = PotManager::new(self.players.len());
+        self.in_progress = false;
+        Ok(())
+    }
+
+    /// Tells the observers and keeps the event for [`Engine::drain_events`].
+    fn emit(&mut self, event: GameEvent) {
+        for (perspective, observer) in self.observers.iter_mut() {
+            if perspective.sees(&event) {
+                observer.observe(&event);
+            }
+        }
+        self.events.push(event);
+    }
+
+    /// Which seats still have chips to play with.
+    fn active_seats(&self) -> Vec<bool> {
+        self.players.iter().map(|p| p.stack > 0).collect()
+    }
+
+    /// Posts the small and big blind. There's no small blind if that seat has busted.
+    fn post_blinds(&mut self, positions: TablePositions) {
+        let TablePositions {
+            small_blind,
+            big_blind,
+            ..
+        } = positions;
+        if self.players[small_blind].stack > 0 {
+            self.post_blind(small_blind, Blind::Small, self.small_blind);
+        }
+        self.post_blind(big_blind, Blind::Big, self.big_blind);
+    }
+
+    fn post_blind(&mut self, seat: usize, blind: Blind, amount: u32) {
+        let stack = self.players[seat].stack;
+        let amount = self.betting.post_blind(seat, amount, stack);
+        self.commit(seat, amount);
+        self.emit(GameEvent::BlindPosted {
+            seat,
+            blind,
+            amount,
+        });
+    }
+
+    fn deal_cards(&mut self) {
+        for i in 0..self.players.len() {
+            if self.betting.has_folded(i) {
+                // no chips left to play with
+                continue;
+            }
+            let player_hand = &mut self.players[i];
+
+            // everyone gets two cards whatever the table size, see MAX_PLAYERS
+            let cards = self
+                .deck
+                .deal_many(2)
+                .expect("there are cards for every seat");
+            player_hand.cards = cards.clone();
+            self.emit(GameEvent::HoleCardsDealt { seat: i, cards });
+        }
+    }
+
+    /// Moves chips from the player's stack into the pot.
+    fn commit(&mut self, player: usize, amount: u32) {
+        self.players[player].stack -= amount;
+        self.pots.contribute(player, amount);
+    }
+
+    /// The value of every player's best hand, `None` for anyone who folded. Nobody has to show
+    /// their cards if everyone else folded, and there may not even be a board.
+    fn hand_values(&self) -> Vec<Option<HandValue>> {
+        let everyone_folded = self.betting.players_in_hand() == 1;
+        self.players
+            .iter()
+            .enumerate()
+            .map(|(i, player)| {
+                if self.betting.has_folded(i) || everyone_folded {
+                    None
+                } else {
+                    Some(best_hand(&player.cards, &self.shared_cards).value)
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,513 @@
+//! How often each of a set of hole-card hands wins once the rest of the board is dealt.
+//!
+//! Runouts are scored with the table driven [`evaluator`](crate::evaluator), so even a few
+//! hundred thousand samples only take a moment. Hands can also be whole [`Range`]s, see
+//! [`range_equity`].
+
+use crate::evaluator::{best_strength, encode, encode_packed};
+use crate::{Card, CardError, CardSet, Combo, PackedCard, Range};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::error::Error;
+use std::fmt;
+
+/// Why the hands and board can't be run out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquityError {
+    /// Need at least two hands to compare.
+    TooFewHands,
+    /// Every hand has to be exactly two hole cards.
+    WrongNumberOfCards { hand: usize, count: usize },
+    /// The board can have at most five cards.
+    BoardTooBig { count: usize },
+    /// A card is in two places at once, between the hands, board and dead cards, or isn't a
+    /// card at all.
+    Card(CardError),
+    /// Too many cards are in use to fill the board.
+    NotEnoughCards { needed: usize, remaining: usize },
+    /// Every combo in the range uses a card on the board or a dead card.
+    EmptyRange { range: usize },
+    /// The ranges kept needing the same cards, so hands that don't clash couldn't be dealt.
+    RangesConflict,
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EquityError::TooFewHands => write!(f, "need at least two hands to compare"),
+            EquityError::WrongNumberOfCards { hand, count } => write!(
+                f,
+                "hand {} has {} cards, every hand needs exactly two",
+                hand + 1,
+                count
+            ),
+            EquityError::BoardTooBig { count } => {
+                write!(f, "the board has {} cards, it can have at most five", count)
+            }
+            EquityError::Card(err) => write!(f, "{}", err),
+            EquityError::NotEnoughCards { needed, remaining } => write!(
+                f,
+                "need {} cards to finish the board but only {} are left",
+                needed, remaining
+            ),
+            EquityError::EmptyRange { range } => write!(
+                f,
+                "range {} has no combos left once the board and dead cards are taken out",
+                range + 1
+            ),
+            EquityError::RangesConflict => {
+                write!(
+                    f,
+                    "couldn't deal hands from the ranges that don't share cards"
+                )
+            }
+        }
+    }
+}
+
+impl Error for EquityError {}
+
+impl From<CardError> for EquityError {
+    fn from(err: CardError) -> Self {
+        EquityError::Card(err)
+    }
+}
+
+/// How one hand did over every runout.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HandEquity {
+    pub wins: u64,
+    pub ties: u64,
+    pub losses: u64,
+    /// Pots won, with a tied pot counting as the share of it this hand gets.
+    pub pots: f64,
+}
+
+impl HandEquity {
+    pub fn runouts(&self) -> u64 {
+        self.wins + self.ties + self.losses
+    }
+
+    pub fn win_percent(&self) -> f64 {
+        self.percent(self.wins as f64)
+    }
+
+    pub fn tie_percent(&self) -> f64 {
+        self.percent(self.ties as f64)
+    }
+
+    pub fn lose_percent(&self) -> f64 {
+        self.percent(self.losses as f64)
+    }
+
+    /// Share of the pot the hand can expect, wins plus its part of any ties.
+    pub fn equity_percent(&self) -> f64 {
+        self.percent(self.pots)
+    }
+
+    fn add(&mut self, other: &HandEquity) {
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.losses += other.losses;
+        self.pots += other.pots;
+    }
+
+    fn percent(&self, count: f64) -> f64 {
+        match self.runouts() {
+            0 => 0.0,
+            runouts => 100.0 * count / runouts as f64,
+        }
+    }
+}
+
+/// The result for each hand, in the order they were given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equity {
+    pub hands: Vec<HandEquity>,
+    /// How many boards were looked at.
+    pub runouts: u64,
+    /// Every possible runout was looked at once, so the counts are exact rather than sampled.
+    pub exact: bool,
+}
+
+/// Works out equity exactly when there are no more runouts than `iterations`, and by
+/// [`monte_carlo`] sampling otherwise. Check [`Equity::exact`] to see which it was.
+pub fn calculate(
+    hands: &[Vec<Card>],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u32,
+    seed: Option<u64>,
+) -> Result<Equity, EquityError> {
+    let setup = Setup::new(hands, board, dead)?;
+    if runout_count(setup.deck.len(), setup.missing) <= iterations as u64 {
+        Ok(setup.enumerate())
+    } else {
+        Ok(setup.sample(iterations, seed))
+    }
+}
+
+/// Works out equity by dealing every possible runout from the cards nobody holds once. On the
+/// flop or later that's at most a thousand or so boards, preflop it's well over a million.
+/// # Example
+/// Ah Kh vs 7c 7d on Qh Jh 2c -> exactly 990 runouts
+pub fn exact(hands: &[Vec<Card>], board: &[Card], dead: &[Card]) -> Result<Equity, EquityError> {
+    Ok(Setup::new(hands, board, dead)?.enumerate())
+}
+
+/// How many different ways there are to deal `missing` board cards from `remaining`.
+fn runout_count(remaining: usize, missing: usize) -> u64 {
+    (0..missing as u64).fold(1, |count, i| count * (remaining as u64 - i) / (i + 1))
+}
+
+/// Estimates equity by dealing `iterations` random boards from the cards nobody holds. The
+/// same `seed` always deals the same boards; `None` picks one at random. With a complete board
+/// there's only one runout, so it's scored once whatever `iterations` is.
+/// # Example
+/// As Ks vs Qd Qc preflop -> roughly 46% vs 54%
+pub fn monte_carlo(
+    hands: &[Vec<Card>],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u32,
+    seed: Option<u64>,
+) -> Result<Equity, EquityError> {
+    Ok(Setup::new(hands, board, dead)?.sample(iterations, seed))
+}
+
+/// How one of the hero's combos did.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComboEquity {
+    pub combo: Combo,
+    pub equity: HandEquity,
+}
+
+/// The result for each range, in the order they were given, and a breakdown of the first one,
+/// the hero's.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeEquity {
+    pub ranges: Vec<HandEquity>,
+    /// Each of the hero's combos that doesn't use a board or dead card, in range order.
+    pub hero_combos: Vec<ComboEquity>,
+    /// How many deals, hands and board together, were looked at.
+    pub runouts: u64,
+}
+
+/// How many times to try dealing hands from the ranges before giving up on them ever fitting
+/// together.
+const MAX_DEAL_ATTEMPTS: u32 = 10_000;
+
+/// Estimates equity when each player holds a range rather than a fixed hand. For each of the
+/// `iterations` deals every player gets a combo from their range, picked by weight, with deals
+/// where two players would share a card thrown away; then the board is filled in from what's
+/// left. Combos that use a board or dead card are never dealt.
+/// # Example
+/// "QQ+, AKs" vs "TT-88, AQs+" preflop
+pub fn range_equity(
+    ranges: &[Range],
+    board: &[Card],
+    dead: &[Card],
+    iterations: u32,
+    seed: Option<u64>,
+) -> Result<RangeEquity, EquityError> {
+    if ranges.len() < 2 {
+        return Err(EquityError::TooFewHands);
+    }
+    if board.len() > 5 {
+        return Err(EquityError::BoardTooBig { count: board.len() });
+    }
+
+    let mut used = CardSet::new();
+    let board = board
+        .iter()
+        .map(|card| claim(&mut used, card))
+        .collect::<Result<Vec<_>, EquityError>>()?;
+    for card in dead {
+        claim(&mut used, card)?;
+    }
+
+    let live: Vec<LiveRange> = ranges
+        .iter()
+        .enumerate()
+        .map(|(range, combos)| {
+            let live = LiveRange::new(combos, used);
+            if live.combos.is_empty() {
+                return Err(EquityError::EmptyRange { range });
+            }
+            Ok(live)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let deck: Vec<PackedCard> = (!used).iter().collect();
+    let missing = 5 - board.len();
+    let needed = missing + 2 * ranges.len();
+    if needed > deck.len() {
+        return Err(EquityError::NotEnoughCards {
+            needed,
+            remaining: deck.len(),
+        });
+    }
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut results = vec![HandEquity::default(); ranges.len()];
+    let mut hero_combos: Vec<ComboEquity> = live[0]
+        .combos
+        .iter()
+        .map(|live| ComboEquity {
+            combo: live.combo.clone(),
+            equity: HandEquity::default(),
+        })
+        .collect();
+
+    let mut runout = board.clone();
+    for _ in 0..iterations {
+        let (picked, mut taken) = deal_hands(&live, &mut rng)?;
+        let hands: Vec<[u32; 2]> = picked
+            .iter()
+            .zip(&live)
+            .map(|(index, range)| range.combos[*index].cards)
+            .collect();
+
+        runout.truncate(board.len());
+        while runout.len() < 5 {
+            let card = deck[rng.gen_range(0..deck.len())];
+            if taken.insert(card) {
+                runout.push(encode_packed(card));
+            }
+        }
+
+        let mut deal = vec![HandEquity::default(); ranges.len()];
+        score(&hands, &runout, &mut deal);
+        for (total, result) in results.iter_mut().zip(&deal) {
+            total.add(result);
+        }
+        hero_combos[picked[0]].equity.add(&deal[0]);
+    }
+
+    Ok(RangeEquity {
+        ranges: results,
+        hero_combos,
+        runouts: iterations as u64,
+    })
+}
+
+/// A range with the combos that clash with the board or dead cards taken out.
+struct LiveRange {
+    combos: Vec<LiveCombo>,
+    /// Running total of the weights, for picking a combo by weight.
+    cumulative: Vec<f64>,
+}
+
+struct LiveCombo {
+    combo: Combo,
+    set: CardSet,
+    cards: [u32; 2],
+}
+
+impl LiveRange {
+    fn new(range: &Range, used: CardSet) -> LiveRange {
+        let combos: Vec<LiveCombo> = range
+            .combos()
+            .iter()
+            .filter(|combo| combo.weight > 0.0 && combo.card_set().is_disjoint(used))
+            .map(|combo| LiveCombo {
+                combo: combo.clone(),
+                set: combo.card_set(),
+                cards: [encode(&combo.cards[0]), encode(&combo.cards[1])],
+            })
+            .collect();
+        let cumulative = combos
+            .iter()
+            .scan(0.0, |total, live| {
+                *total += live.combo.weight;
+                Some(*total)
+            })
+            .collect();
+        LiveRange { combos, cumulative }
+    }
+
+    fn pick<R: Rng>(&self, rng: &mut R) -> usize {
+        let total = self.cumulative[self.cumulative.len() - 1];
+        let target = rng.gen::<f64>() * total;
+        self.cumulative
+            .partition_point(|weight| *weight <= target)
+            .min(self.combos.len() - 1)
+    }
+}
+
+/// Picks a combo from each range that doesn't share a card with any other, returning the index
+/// of each and all the cards they use.
+fn deal_hands<R: Rng>(
+    ranges: &[LiveRange],
+    rng: &mut R,
+) -> Result<(Vec<usize>, CardSet), EquityError> {
+    'attempt: for _ in 0..MAX_DEAL_ATTEMPTS {
+        let mut taken = CardSet::new();
+        let mut picked = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            let index = range.pick(rng);
+            let set = range.combos[index].set;
+            if !set.is_disjoint(taken) {
+                continue 'attempt;
+            }
+            taken |= set;
+            picked.push(index);
+        }
+        return Ok((picked, taken));
+    }
+    Err(EquityError::RangesConflict)
+}
+
+/// The hands, board and the cards left to deal from, already checked and encoded for the
+/// evaluator.
+#[derive(Debug, Clone)]
+struct Setup {
+    hands: Vec<[u32; 2]>,
+    board: Vec<u32>,
+    deck: Vec<u32>,
+    /// Board cards still to come.
+    missing: usize,
+}
+
+impl Setup {
+    fn new(hands: &[Vec<Card>], board: &[Card], dead: &[Card]) -> Result<Setup, EquityError> {
+        if hands.len() < 2 {
+            return Err(EquityError::TooFewHands);
+        }
+        for (hand, cards) in hands.iter().enumerate() {
+            if cards.len() != 2 {
+                return Err(EquityError::WrongNumberOfCards {
+                    hand,
+                    count: cards.len(),
+                });
+            }
+        }
+        if board.len() > 5 {
+            return Err(EquityError::BoardTooBig { count: board.len() });
+        }
+
+        let mut used = CardSet::new();
+        let mut claim = |card: &Card| claim(&mut used, card);
+
+        let hands = hands
+            .iter()
+            .map(|cards| Ok([claim(&cards[0])?, claim(&cards[1])?]))
+            .collect::<Result<Vec<_>, EquityError>>()?;
+        let board = board
+            .iter()
+            .map(&mut claim)
+            .collect::<Result<Vec<_>, EquityError>>()?;
+        for card in dead {
+            claim(card)?;
+        }
+
+        let deck: Vec<u32> = (!used).iter().map(encode_packed).collect();
+        let missing = 5 - board.len();
+        if missing > deck.len() {
+            return Err(EquityError::NotEnoughCards {
+                needed: missing,
+                remaining: deck.len(),
+            });
+        }
+
+        Ok(Setup {
+            hands,
+            board,
+            deck,
+            missing,
+        })
+    }
+
+    /// Scores every way the rest of the board can come.
+    fn enumerate(&self) -> Equity {
+        let mut results = vec![HandEquity::default(); self.hands.len()];
+        let mut runout = self.board.clone();
+        let mut runouts = 0;
+        for cards in self.deck.iter().combinations(self.missing) {
+            runout.truncate(self.board.len());
+            runout.extend(cards);
+            score(&self.hands, &runout, &mut results);
+            runouts += 1;
+        }
+
+        Equity {
+            hands: results,
+            runouts,
+            exact: true,
+        }
+    }
+
+    /// Scores `iterations` random ways the rest of the board can come, or the board itself if
+    /// it's already complete.
+    fn sample(&self, iterations: u32, seed: Option<u64>) -> Equity {
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut results = vec![HandEquity::default(); self.hands.len()];
+        if self.missing == 0 {
+            score(&self.hands, &self.board, &mut results);
+            return Equity {
+                hands: results,
+                runouts: 1,
+                exact: true,
+            };
+        }
+
+        let mut deck = self.deck.clone();
+        let mut runout = self.board.clone();
+        for _ in 0..iterations {
+            // a partial shuffle puts a random set of cards at the front of the deck
+            runout.truncate(self.board.len());
+            for i in 0..self.missing {
+                let j = rng.gen_range(i..deck.len());
+                deck.swap(i, j);
+                runout.push(deck[i]);
+            }
+            score(&self.hands, &runout, &mut results);
+        }
+
+        Equity {
+            hands: results,
+            runouts: iterations as u64,
+            exact: false,
+        }
+    }
+}
+
+/// Adds the card to `used`, returning it encoded for the evaluator.
+fn claim(used: &mut CardSet, card: &Card) -> Result<u32, EquityError> {
+    Ok(encode_packed(used.claim(card)?))
+}
+
+/// Scores one complete board for every hand.
+fn score(hands: &[[u32; 2]], board: &[u32], results: &mut [HandEquity]) {
+    let mut cards = [0; 7];
+    cards[2..].copy_from_slice(board);
+    let strengths: Vec<u16> = hands
+        .iter()
+        .map(|hand| {
+            cards[..2].copy_from_slice(hand);
+            best_strength(&cards)
+        })
+        .collect();
+
+    let best = *strengths.iter().max().expect("there are always hands");
+    let winners = strengths.iter().filter(|s| **s == best).count();
+    for (result, strength) in results.iter_mut().zip(strengths) {
+        if strength < best {
+            result.losses += 1;
+        } else if winners == 1 {
+            result.wins += 1;
+            result.pots += 1.0;
+        } else {
+            result.ties += 1;
+            result.pots += 1.0 / winners as f64;
+        }
+    }
+}
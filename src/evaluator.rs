@@ -0,0 +1,243 @@
+//! Table driven hand evaluator for when hands need ranking millions of times.
+//!
+//! Cards are encoded the way Cactus Kev's evaluator does it, one `u32` per card:
+//!
+//! ```text
+//! +--------+--------+--------+--------+
+//! |xxxbbbbb|bbbbbbbb|cdhsrrrr|xxpppppp|
+//! +--------+--------+--------+--------+
+//! ```
+//!
+//! where `b` is one bit per rank, `cdhs` is the suit, `r` is the rank index and `p` is the rank's
+//! prime. A 5 card hand is then looked up without any allocation: flushes and hands with five
+//! distinct ranks are indexed by their rank bits, and everything else by the product of the
+//! primes, which is unique for each combination of ranks.
+//!
+//! Each distinct hand gets a strength from 1 (7-5-4-3-2 offsuit) to 7462 (a royal flush), so two
+//! hands compare the same way their [`HandValue`]s do. The tables are built from [`hand_value`]
+//! the first time they're needed.
+
+use crate::{hand_value as reference_hand_value, Card, CardSet, HandRank, HandValue};
+use crate::{PackedCard, Rank, Suit};
+use itertools::Itertools;
+use std::sync::OnceLock;
+use strum::IntoEnumIterator;
+
+/// Number of 5 card hands that can be told apart once suits only matter for flushes.
+pub const DISTINCT_HANDS: u16 = 7462;
+
+const PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+struct Tables {
+    flushes: Vec<u16>,
+    unique5: Vec<u16>,
+    // sorted by prime product so it can be binary searched
+    products: Vec<(u32, u16)>,
+    // indexed by strength - 1
+    values: Vec<HandValue>,
+}
+
+/// Returns the hand rank of the best 5 cards in the hand, same as [`crate::rank_hand`].
+/// # Panics
+/// If there are fewer than five cards, or any card isn't one of the 52 in a deck.
+pub fn rank_hand(hand: &[&Card]) -> HandRank {
+    strength_rank(hand_strength(hand))
+}
+
+/// Returns the hand rank a strength from [`best_strength`] or [`evaluate`] belongs to.
+/// # Panics
+/// If the strength isn't between 1 and [`DISTINCT_HANDS`].
+pub fn strength_rank(strength: u16) -> HandRank {
+    tables().values[strength as usize - 1].rank
+}
+
+/// Returns the value of the best 5 cards in the hand, same as [`crate::hand_value`].
+/// # Panics
+/// If there are fewer than five cards, or any card isn't one of the 52 in a deck.
+pub fn hand_value(hand: &[&Card]) -> HandValue {
+    tables().values[hand_strength(hand) as usize - 1].clone()
+}
+
+/// Returns the strength of the best 5 cards in the hand, between 1 and [`DISTINCT_HANDS`]. Hands
+/// with more than 5 cards are scored by their best 5.
+/// # Panics
+/// If there are fewer than five cards in the hand, or any card isn't one of the 52 in a deck.
+pub fn hand_strength(hand: &[&Card]) -> u16 {
+    let encoded: Vec<u32> = hand.iter().map(|card| encode(card)).collect();
+    best_strength(&encoded)
+}
+
+/// Returns the strength of the best 5 of the already encoded cards.
+/// # Panics
+/// If there are fewer than five cards.
+pub fn best_strength(cards: &[u32]) -> u16 {
+    assert!(cards.len() >= 5, "need at least five cards to make a hand");
+    if cards.len() == 5 {
+        return evaluate([cards[0], cards[1], cards[2], cards[3], cards[4]]);
+    }
+    // hole cards plus a turn or river board are common enough to do without allocating, by
+    // leaving out each card or pair of cards in turn
+    if cards.len() == 6 {
+        return (0..6)
+            .map(|skip| evaluate(five_without(cards, skip, skip)))
+            .max()
+            .expect("there is always at least one combination");
+    }
+    if cards.len() == 7 {
+        return (0..7)
+            .flat_map(|first| (first + 1..7).map(move |second| (first, second)))
+            .map(|(first, second)| evaluate(five_without(cards, first, second)))
+            .max()
+            .expect("there is always at least one combination");
+    }
+
+    cards
+        .iter()
+        .combinations(5)
+        .map(|c| evaluate([*c[0], *c[1], *c[2], *c[3], *c[4]]))
+        .max()
+        .expect("there is always at least one combination")
+}
+
+/// The five cards left after taking out the ones at `first` and `second`, which can be the
+/// same index when there are six cards.
+fn five_without(cards: &[u32], first: usize, second: usize) -> [u32; 5] {
+    let mut hand = [0; 5];
+    let kept = cards
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != first && *i != second)
+        .map(|(_, card)| *card);
+    for (slot, card) in hand.iter_mut().zip(kept) {
+        *slot = card;
+    }
+    hand
+}
+
+/// Returns the strength of the best 5 cards in the set.
+/// # Panics
+/// If there are fewer than five cards in the set.
+pub fn set_strength(cards: CardSet) -> u16 {
+    let encoded: Vec<u32> = cards.iter().map(encode_packed).collect();
+    best_strength(&encoded)
+}
+
+/// Returns the strength of exactly 5 encoded cards.
+pub fn evaluate(cards: [u32; 5]) -> u16 {
+    let tables = tables();
+    let [c1, c2, c3, c4, c5] = cards;
+    let rank_bits = ((c1 | c2 | c3 | c4 | c5) >> 16) as usize;
+
+    if c1 & c2 & c3 & c4 & c5 & 0xF000 != 0 {
+        return tables.flushes[rank_bits];
+    }
+
+    let unique = tables.unique5[rank_bits];
+    if unique != 0 {
+        return unique;
+    }
+
+    let product = (c1 & 0xFF) * (c2 & 0xFF) * (c3 & 0xFF) * (c4 & 0xFF) * (c5 & 0xFF);
+    let i = tables
+        .products
+        .binary_search_by_key(&product, |(p, _)| *p)
+        .expect("every prime product with repeated ranks is in the table");
+    tables.products[i].1
+}
+
+/// Encodes a card into the layout described in the module docs.
+/// # Panics
+/// If the card isn't one of the 52 in a deck.
+pub fn encode(card: &Card) -> u32 {
+    encode_packed(PackedCard::try_from(card).expect("can only evaluate cards from the deck"))
+}
+
+/// Encodes a packed card into the layout described in the module docs.
+pub fn encode_packed(card: PackedCard) -> u32 {
+    let rank = card.rank_index() as usize;
+    let suit = match card.suit() {
+        Suit::Spades => 0x1000,
+        Suit::Hearts => 0x2000,
+        Suit::Diamonds => 0x4000,
+        Suit::Clubs => 0x8000,
+    };
+    (1 << (16 + rank)) | suit | ((rank as u32) << 8) | PRIMES[rank]
+}
+
+fn tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(build_tables)
+}
+
+fn build_tables() -> Tables {
+    let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+    // every way of picking 5 ranks with no rank more than 4 times, once as a flush where that's
+    // possible and once without
+    let mut hands: Vec<(Vec<Card>, HandValue)> = vec![];
+    for ranks in Rank::iter().combinations_with_replacement(5) {
+        if ranks.iter().dedup_with_count().any(|(count, _)| count > 4) {
+            continue;
+        }
+
+        // ranks come out sorted so repeats sit next to each other and get different suits
+        let offsuit: Vec<Card> = ranks
+            .iter()
+            .enumerate()
+            .map(|(i, rank)| Card::new(*rank, suits[i % 4]))
+            .collect();
+        hands.push(with_value(offsuit));
+
+        if ranks.iter().all_unique() {
+            let suited: Vec<Card> = ranks
+                .iter()
+                .map(|rank| Card::new(*rank, Suit::Hearts))
+                .collect();
+            hands.push(with_value(suited));
+        }
+    }
+
+    let values: Vec<HandValue> = hands
+        .iter()
+        .map(|(_, value)| value.clone())
+        .sorted()
+        .dedup()
+        .collect();
+    assert_eq!(values.len(), DISTINCT_HANDS as usize);
+
+    let mut tables = Tables {
+        flushes: vec![0; 1 << 13],
+        unique5: vec![0; 1 << 13],
+        products: vec![],
+        values,
+    };
+
+    for (cards, value) in &hands {
+        let strength = tables
+            .values
+            .binary_search(value)
+            .expect("every value was collected above") as u16
+            + 1;
+        let encoded: Vec<u32> = cards.iter().map(encode).collect();
+        let rank_bits = (encoded.iter().fold(0, |bits, c| bits | c) >> 16) as usize;
+        let is_flush = cards.iter().all(|c| c.suit == cards[0].suit);
+
+        if is_flush {
+            tables.flushes[rank_bits] = strength;
+        } else if rank_bits.count_ones() == 5 {
+            tables.unique5[rank_bits] = strength;
+        } else {
+            let product = encoded.iter().map(|c| c & 0xFF).product();
+            tables.products.push((product, strength));
+        }
+    }
+    tables.products.sort();
+
+    tables
+}
+
+fn with_value(cards: Vec<Card>) -> (Vec<Card>, HandValue) {
+    let hand: Vec<&Card> = cards.iter().collect();
+    let value = reference_hand_value(&hand);
+    (cards, value)
+}
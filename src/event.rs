@@ -0,0 +1,70 @@
+use crate::{Action, Card, PotAward, Round, TablePositions};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blind {
+    Small,
+    Big,
+}
+
+/// Something that happened at the table, in the order it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    /// A new hand has started, `hand` counts from 1. The deck was shuffled from `seed`, see
+    /// [`Engine::start_hand_with_seed`](crate::Engine::start_hand_with_seed).
+    HandStarted {
+        hand: u32,
+        seed: u64,
+        positions: TablePositions,
+    },
+    /// `amount` can be less than the blind if the seat didn't have enough chips.
+    BlindPosted {
+        seat: usize,
+        blind: Blind,
+        amount: u32,
+    },
+    /// Only observers for the seat itself, or omniscient ones, are told about these, see
+    /// [`Perspective`].
+    HoleCardsDealt { seat: usize, cards: Vec<Card> },
+    PlayerActed {
+        seat: usize,
+        action: Action,
+        amount: u32,
+    },
+    /// The cards just turned over for the round, not the whole board.
+    BoardDealt { round: Round, cards: Vec<Card> },
+    /// A seat still in at the end of the hand turned its cards over.
+    CardsShown { seat: usize, cards: Vec<Card> },
+    /// One of these for each pot, main pot first.
+    PotAwarded(PotAward),
+    /// The pots have been paid out, `stacks` are everyone's chips going into the next hand.
+    HandEnded { stacks: Vec<u32> },
+}
+
+/// Which hole cards an observer is told about. Everything else that happens is public.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Perspective {
+    /// What everyone at the table sees, hole cards only once they're shown.
+    Table,
+    /// What the seat sees, its own hole cards as well.
+    Seat(usize),
+    /// Every seat's hole cards, for logs and replays rather than players.
+    Omniscient,
+}
+
+impl Perspective {
+    /// Whether an observer with this perspective gets to see the event.
+    pub fn sees(&self, event: &GameEvent) -> bool {
+        match (self, event) {
+            (Perspective::Omniscient, _) => true,
+            (Perspective::Seat(own), GameEvent::HoleCardsDealt { seat, .. }) => own == seat,
+            (_, GameEvent::HoleCardsDealt { .. }) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Gets told about what happens as the engine runs, as much as its [`Perspective`] allows, see
+/// [`Engine::add_observer`](crate::Engine::add_observer).
+pub trait GameObserver {
+    fn observe(&mut self, event: &GameEvent);
+}
@@ -1,15 +1,47 @@
-use itertools::{Combinations, Itertools};
-use rand::seq::SliceRandom;
-use rand::thread_rng;
+mod agent;
+mod betting;
+mod card_set;
+mod console;
+mod deck;
+mod engine;
+pub mod equity;
+pub mod evaluator;
+mod event;
+mod notation;
+mod outs;
+mod packed_card;
+mod positions;
+mod pot;
+mod range;
+mod view;
+
+pub use agent::{PlayerAgent, ScriptedAgent, TerminalAgent};
+pub use betting::{Action, ActionError, BettingRound};
+pub use card_set::{CardError, CardSet, CardSetIter};
+pub use console::ConsoleObserver;
+pub use deck::{Deck, DeckExhausted};
+pub use engine::{Engine, EngineError, MAX_PLAYERS};
+pub use event::{Blind, GameEvent, GameObserver, Perspective};
+pub use notation::{parse_cards, ParseCardError};
+pub use outs::{analyse_outs, Draw, DrawAnalysis, Outs, OutsError};
+pub use packed_card::PackedCard;
+pub use positions::TablePositions;
+pub use pot::{OddChipRule, Pot, PotAward, PotManager};
+pub use range::{Combo, ParseRangeError, Range, RangeError};
+pub use view::{ActionRecord, PlayerView};
+
+use itertools::Itertools;
 use std::cmp::{Eq, Ordering, PartialEq};
 use std::collections::HashMap;
-use std::collections::HashSet;
-use std::{fmt, io};
-use strum::IntoEnumIterator;
+use std::fmt;
 use strum_macros::EnumIter;
 
+/// Chips each player starts the game with.
+pub const STARTING_STACK: u32 = 100;
+
 pub struct Config {
     pub number_of_players: u8,
+    pub odd_chip_rule: OddChipRule,
 }
 
 impl Config {
@@ -23,100 +55,83 @@ impl Config {
             .parse()
             .expect("couldn't parse number of players");
 
-        Ok(Config { number_of_players })
+        // who gets the odd chips from a split pot, "button" (the default) or "suit"
+        let odd_chip_rule = match args.get(2).map(|arg| arg.as_str()) {
+            None | Some("button") => OddChipRule::LeftOfButton,
+            Some("suit") => OddChipRule::HighestCardBySuit,
+            Some(_) => return Err("odd chip rule must be \"button\" or \"suit\""),
+        };
+
+        Ok(Config {
+            number_of_players,
+            odd_chip_rule,
+        })
     }
 }
 
-pub fn play_game(num_players: u8) -> Result<(), &'static str> {
-    let mut game = Game::new(&num_players)?;
-    game.shuffle_cards();
-
-    game.deal_cards();
+/// Plays a game at the terminal, everyone taking turns at the keyboard. Returns everyone's
+/// stack at the end.
+pub fn play_game(config: &Config) -> Result<Vec<u32>, EngineError> {
+    let mut agents: Vec<Box<dyn PlayerAgent>> = (0..config.number_of_players)
+        .map(|_| Box::new(TerminalAgent::new()) as Box<dyn PlayerAgent>)
+        .collect();
+    let mut engine = Engine::new(agents.len())?;
+    engine.set_odd_chip_rule(config.odd_chip_rule);
+    engine.add_observer(Box::new(ConsoleObserver::new()));
+    play_session(&mut engine, &mut agents)
+}
 
-    // for all rounds
-    while game.is_valid() {
-        match game.round {
-            Round::PreFlop => {
-                // do betting and choosing
-                println!("ROUND::pre flop");
-                println!("---------------");
-                game = game.run_game_loop();
-                game.round = Round::Flop
-            }
-            Round::Flop => {
-                // show three cards
-                game.shared_cards
-                    .push(game.cards.pop().expect("somehow ran out of cards"));
-                game.shared_cards
-                    .push(game.cards.pop().expect("somehow ran out of cards"));
-                game.shared_cards
-                    .push(game.cards.pop().expect("somehow ran out of cards"));
-                println!("ROUND::flop");
-                println!("---------------");
-                game = game.run_game_loop();
-                game.round = Round::Turn
-            }
-            Round::Turn => {
-                game.shared_cards
-                    .push(game.cards.pop().expect("somehow ran out of cards"));
-                // do betting and choosing
-                println!("ROUND::turn");
-                println!("---------------");
-                game = game.run_game_loop();
-                game.round = Round::River
-            }
-            Round::River => {
-                game.shared_cards
-                    .push(game.cards.pop().expect("somehow ran out of cards"));
-                println!("ROUND::river");
-                println!("---------------");
-                game = game.run_game_loop();
-                break;
+/// How many illegal actions in a row an agent gets before its seat checks, or folds if it
+/// can't check, instead.
+const MAX_REJECTED_ACTIONS: u32 = 10;
+
+/// Plays hands on the engine between the agents, one per seat, until one of them has all the
+/// chips or an agent leaves. Returns everyone's stack at the end. An agent that keeps choosing
+/// illegal actions has its seat check or fold for it.
+pub fn play_session(
+    engine: &mut Engine,
+    agents: &mut [Box<dyn PlayerAgent>],
+) -> Result<Vec<u32>, EngineError> {
+    if agents.len() != engine.num_seats() {
+        return Err(EngineError::WrongNumberOfAgents {
+            agents: agents.len(),
+            seats: engine.num_seats(),
+        });
+    }
+
+    // keep dealing hands until one player has all the chips
+    while !engine.is_game_over() {
+        engine.start_hand()?;
+        let mut rejected = 0;
+        while let Some(seat) = engine.to_act() {
+            let legal_actions = engine.legal_actions();
+            let action = if rejected < MAX_REJECTED_ACTIONS {
+                agents[seat].act(&engine.view(seat)?, &legal_actions)
+            } else if legal_actions.contains(&Action::Check) {
+                Action::Check
+            } else {
+                Action::Fold
+            };
+
+            // asking again if it wasn't a legal move
+            match engine.apply(seat, action) {
+                Ok(()) => rejected = 0,
+                Err(EngineError::IllegalAction(err)) => {
+                    rejected += 1;
+                    agents[seat].rejected(action, &err);
+                }
+                Err(err) => return Err(err),
             }
         }
-    }
-
-    // figure out who won
-    println!("player hands {:?}", game.players);
-    // go through all players cards and generate the different hand possiblites
-    // 7 choose 5 for both players
-
-    let mut player_one_cards: Vec<Card> = game.players[0].cards.clone();
-    let mut game_cards = game.shared_cards.clone();
-    player_one_cards.append(&mut game_cards);
-    println!("player 1 cards {:?}", player_one_cards);
+        // nobody is reading these, the observers have already seen them
+        engine.drain_events();
 
-    // this is not correct, this will sometimes return combinations that are just the cards on the table and not any
-    // in the players hands. The correct way would have to be
-    // * both cards in hand + all combinations of remaining three
-    // * one card in hard + all combinations of remaining four
-    // * same thing for other card
-    let combinations: Combinations<std::slice::Iter<Card>> =
-        player_one_cards.iter().combinations(5);
-    println!("combinations");
-
-    // sort each of the different combinations
-    let foo: Vec<Vec<&Card>> = combinations
-        .into_iter()
-        .map(|h| {
-            let mut h = h.to_vec();
-            h.sort();
-            h
-        })
-        .collect();
-
-    for v in foo {
-        print_cards(&v);
-        print!("rank: {:?}", rank_hand(&v));
-        println!();
+        if agents.iter().any(|agent| agent.has_left()) {
+            break;
+        }
     }
 
-    // run handrank on them
-    // pull the top rank for all players
-    // compare them and declare winner
-
-    println!("GAME OVER");
-    Ok(())
+    Ok(engine.stacks())
 }
 
 fn print_cards(cards: &Vec<&Card>) {
@@ -141,173 +156,129 @@ fn print_table_cards(cards: &Vec<Card>) {
     println!();
 }
 
-#[derive(Debug)]
-struct Game {
-    cards: Vec<Card>,
-    players: Vec<Player>,
-    round: Round,
-    shared_cards: Vec<Card>,
-    current_dealer: u16,
-    current_pot: Vec<Chip>,
-    small_blind: u16,
-    big_blind: u16,
-    folded_player_ids: HashSet<u16>,
-}
-
-impl Game {
-    pub fn new(num_players: &u8) -> Result<Game, &'static str> {
-        let mut cards = Vec::new();
-
-        // initialize all number cards
-        for suit in Suit::iter() {
-            for n in 2..11 {
-                cards.push(Card {
-                    suit: suit,
-                    card_type: CardType::Number { number: n },
-                })
-            }
-        }
+/// Returns the indexes of the players holding the strongest hand on the given board, more than
+/// one when hands tie. Players that have folded are passed as `None` and are never a winner.
+/// # Panics
+/// If any card isn't one found in a deck, see [`Card::rank`].
+pub fn showdown(hands: &[Option<&[Card]>], board: &[Card]) -> Vec<usize> {
+    let values: Vec<(usize, HandValue)> = hands
+        .iter()
+        .enumerate()
+        .filter_map(|(i, hole)| hole.map(|hole| (i, best_hand(hole, board).value)))
+        .collect();
 
-        // initialize all face chards
-        for suit in Suit::iter() {
-            for face in FaceCharacter::iter() {
-                cards.push(Card {
-                    suit: suit,
-                    card_type: CardType::Face {
-                        face_character: face,
-                    },
-                })
-            }
-        }
+    let best = match values.iter().map(|(_, value)| value).max() {
+        Some(best) => best,
+        None => return vec![],
+    };
 
-        // initialize players
-        if num_players < &2 {
-            return Err("not enough players");
-        }
+    values
+        .iter()
+        .filter(|(_, value)| value == best)
+        .map(|(i, _)| *i)
+        .collect()
+}
 
-        Ok(Game {
-            cards,
-            players: (1..num_players + 1)
-                .map(|_| Player {
-                    cards: vec![],
-                    chips: vec![Chip::One, Chip::Five, Chip::TwentyFive, Chip::Fifty],
-                })
-                .collect(),
-            round: Round::PreFlop,
-            shared_cards: vec![],
-            current_dealer: 0,
-            current_pot: vec![],
-            small_blind: 1,
-            big_blind: 2,
-            folded_player_ids: HashSet::new(),
+/// Returns the strongest five cards that can be made from a player's hole cards and the board,
+/// along with their value. Any five cards may be used, including all five from the board.
+/// # Panics
+/// If there are fewer than five cards between the hole cards and the board, or any card isn't
+/// one found in a deck.
+pub fn best_hand(hole: &[Card], board: &[Card]) -> BestHand {
+    hole.iter()
+        .chain(board.iter())
+        .combinations(5)
+        .map(|mut cards| {
+            cards.sort();
+            let value = hand_value(&cards);
+            (cards, value)
         })
-    }
-
-    fn shuffle_cards(&mut self) {
-        self.cards.shuffle(&mut thread_rng());
-    }
-
-    fn deal_cards(&mut self) {
-        for i in 0..self.players.len() {
-            let player_hand = &mut self.players[i];
-
-            let card1 = self.cards.pop().expect("somehow ran out of cards");
-            let card2 = self.cards.pop().expect("somehow ran out of cards");
-
-            player_hand.cards.push(card1);
-            player_hand.cards.push(card2);
-        }
-    }
-
-    fn is_valid(&self) -> bool {
-        self.players.iter().filter(|p| p.cards.len() > 0).count() > 1
-    }
-
-    fn print_game(&self) {
-        println!("pot... {:?}", self.current_pot);
-        // println!("current cards... {:?}", self.shared_cards);
-        print_table_cards(&self.shared_cards);
-        println!("dealer indicator... {:?}", self.current_dealer);
-        println!(
-            "big blin / small blind... {:?} / {:?}",
-            self.small_blind, self.big_blind
-        );
-        println!("");
-    }
-
-    fn run_game_loop(mut self) -> Game {
-        self.print_game();
-        // allow all players to bet
-        for i in 0..self.players.len() {
-            let player = self.players.get(i).expect("should be");
-            if self.folded_player_ids.contains(&i.try_into().unwrap()) {
-                continue;
-            }
-
-            println!("player {}", i + 1);
-            alternate_print_cards(&player.cards);
-            println!("chips: {:?}", player.chips);
-            println!("Choose your move:");
-            println!("- c for check");
-            println!("- b to bet");
-            println!("- f to fold");
-            let mut line = String::new();
-            io::stdin()
-                .read_line(&mut line)
-                .expect("failed to pull guess");
-            println!("input: {}", line);
-
-            // process input
-            if line.trim() == "f" {
-                println!("player {} folded", i + 1);
-                let player_id: u16 = i.clone().try_into().unwrap();
-                self.folded_player_ids.insert(player_id);
-            }
-        }
-        self
-    }
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+        .map(|(cards, value)| BestHand {
+            cards: cards.into_iter().cloned().collect(),
+            value,
+        })
+        .expect("need at least five cards to make a hand")
 }
 
 /// Returns the highest hand rank given a 5 card hand
 /// # Example
 /// 2 Spades, 2 Hearts, Queen Clubs, Queen Hearts, Queen Spades -> FullHouse
-pub fn rank_hand(hand: &Vec<&Card>) -> HandRank {
+/// # Panics
+/// If any card isn't one found in a deck, [`try_hand_value`] returns an error instead.
+pub fn rank_hand(hand: &[&Card]) -> HandRank {
+    hand_value(hand).rank
+}
+
+/// Returns the full value of a 5 card hand, its rank plus the card ranks needed to break a tie
+/// against another hand of the same rank. Values can be compared directly to decide a showdown.
+/// # Example
+/// King Spades, King Hearts, Ace Clubs, 7 Hearts, 3 Spades -> Pair [King, Ace, 7, 3]
+/// # Panics
+/// If any card isn't one found in a deck, [`try_hand_value`] returns an error instead.
+pub fn hand_value(hand: &[&Card]) -> HandValue {
+    try_hand_value(hand).expect("card type is not a valid rank")
+}
+
+/// Same as [`hand_value`], but errors on a card that isn't one found in a deck, like
+/// `CardType::Number { number: 1 }`.
+pub fn try_hand_value(hand: &[&Card]) -> Result<HandValue, &'static str> {
     // basically go through top to bottom and try to match each one,
     // should definitely be able to do this purely functional style, I'm just
     // using a mutable borrow.
 
-    // TODO: sort elsewhere, not here, assume it's in ascending order
+    // the straight checks expect ascending order, so sort a copy rather than trusting the caller
+    let hand: Vec<&Card> = hand.iter().copied().sorted().collect();
 
-    let royal_straight = is_royal_straight(hand);
-    let is_flush = is_flush(hand);
+    let ranks = hand
+        .iter()
+        .map(|card| Rank::try_from(&card.card_type))
+        .collect::<Result<Vec<Rank>, _>>()?;
 
-    if royal_straight && is_flush {
-        return HandRank::RoyalFlush;
+    // count the cards into a heap
+    let mut hash_map: HashMap<Rank, i32> = HashMap::new();
+    for rank in &ranks {
+        let mut val = 1;
+        if hash_map.contains_key(rank) {
+            val = *hash_map.get_mut(rank).unwrap();
+            val += 1;
+        }
+        hash_map.insert(*rank, val);
     }
 
-    let is_straight = is_straight(hand);
+    // biggest groups first, higher ranks first within groups of the same size, this is
+    // exactly the order ties are broken in for every hand rank apart from the straights
+    let groups: Vec<(Rank, i32)> = hash_map
+        .into_iter()
+        .sorted_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.cmp(&a.0)))
+        .collect();
+    let kickers: Vec<Rank> = groups.iter().map(|(rank, _)| *rank).collect();
+    // the ace plays low in a wheel, so the five is the top of that straight
+    let high_card = if is_wheel(&ranks) {
+        vec![Rank::Five]
+    } else {
+        vec![ranks[ranks.len() - 1]]
+    };
 
-    if is_straight && is_flush {
-        return HandRank::StraightFlush;
+    let royal_straight = is_royal_straight(&ranks);
+    let is_flush = is_flush(&hand);
+
+    if royal_straight && is_flush {
+        return Ok(HandValue::new(HandRank::RoyalFlush, high_card));
     }
 
-    // count the cards into a heap
-    let mut hash_map: HashMap<&CardType, i32> = HashMap::new();
-    for card in hand {
-        let mut val = 1;
-        if hash_map.contains_key(&card.card_type) {
-            val = *hash_map.get_mut(&card.card_type).unwrap();
-            val += 1;
-        }
-        hash_map.insert(&card.card_type, val);
+    let is_straight = is_straight(&ranks);
+
+    if is_straight && is_flush {
+        return Ok(HandValue::new(HandRank::StraightFlush, high_card));
     }
 
     // iterate (sorted by values)
     let mut three_of_a_kind = false;
     let mut num_pairs = 0;
-    for (_, v) in hash_map.iter().sorted_by_key(|x| x.1).rev() {
+    for (_, v) in &groups {
         if *v == 4 {
-            return HandRank::FourOfAKind;
+            return Ok(HandValue::new(HandRank::FourOfAKind, kickers));
         }
         if *v == 3 {
             three_of_a_kind = true;
@@ -318,128 +289,53 @@ pub fn rank_hand(hand: &Vec<&Card>) -> HandRank {
     }
 
     if three_of_a_kind && num_pairs > 0 {
-        return HandRank::FullHouse;
+        return Ok(HandValue::new(HandRank::FullHouse, kickers));
     }
 
     if is_flush {
-        return HandRank::Flush;
+        return Ok(HandValue::new(HandRank::Flush, kickers));
     }
 
     if is_straight {
-        return HandRank::Straight;
+        return Ok(HandValue::new(HandRank::Straight, high_card));
     }
 
     if three_of_a_kind {
-        return HandRank::ThreeOfAKind;
+        return Ok(HandValue::new(HandRank::ThreeOfAKind, kickers));
     }
 
     if num_pairs == 2 {
-        return HandRank::TwoPair;
+        return Ok(HandValue::new(HandRank::TwoPair, kickers));
     }
 
     if num_pairs == 1 {
-        return HandRank::Pair;
+        return Ok(HandValue::new(HandRank::Pair, kickers));
     }
 
-    HandRank::HighCard
+    Ok(HandValue::new(HandRank::HighCard, kickers))
 }
 
-fn is_royal_straight(hand: &Vec<&Card>) -> bool {
-    if hand[0].card_type != (CardType::Number { number: 10 }) {
-        return false;
-    }
-    if hand[1].card_type
-        != (CardType::Face {
-            face_character: FaceCharacter::Jack,
-        })
-    {
-        return false;
-    }
-    if hand[2].card_type
-        != (CardType::Face {
-            face_character: FaceCharacter::Queen,
-        })
-    {
-        return false;
-    }
-    if hand[3].card_type
-        != (CardType::Face {
-            face_character: FaceCharacter::King,
-        })
-    {
-        return false;
-    }
-    if hand[4].card_type
-        != (CardType::Face {
-            face_character: FaceCharacter::Ace,
-        })
-    {
-        return false;
-    }
-
-    true
+fn is_royal_straight(ranks: &[Rank]) -> bool {
+    ranks == [Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace]
 }
 
-fn is_straight(hand: &Vec<&Card>) -> bool {
-    for i in 0..4 {
-        let current_card = &hand[i];
-        let next_card = &hand[i + 1];
-
-        let expected_next_card_type = get_next_card_type(&current_card.card_type);
-
-        let next_card_type;
-        match expected_next_card_type {
-            None => return false,
-            Some(card_type) => next_card_type = card_type,
-        }
-
-        if next_card.card_type != next_card_type {
-            return false;
-        }
+fn is_straight(ranks: &[Rank]) -> bool {
+    if is_wheel(ranks) {
+        return true;
     }
 
-    true
+    ranks.windows(2).all(|pair| pair[0].next() == Some(pair[1]))
 }
 
-fn get_next_card_type(card_type: &CardType) -> Option<CardType> {
-    match card_type {
-        CardType::Face { face_character } => match get_next_face_character(face_character) {
-            Some(next_face_character) => {
-                return Some(CardType::Face {
-                    face_character: next_face_character,
-                })
-            }
-            None => return None,
-        },
-        CardType::Number { number } => {
-            if *number == 10 {
-                return Some(CardType::Face {
-                    face_character: FaceCharacter::Jack,
-                });
-            }
-            return Some(CardType::Number { number: number + 1 });
-        }
-    }
+/// Returns true for A-2-3-4-5, the one straight where the Ace counts as low. Sorted ascending the
+/// Ace ends up last, after the five.
+fn is_wheel(ranks: &[Rank]) -> bool {
+    ranks == [Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Ace]
 }
 
-/// Will return the next valid face character or None if the parameter is an Ace
-fn get_next_face_character(face: &FaceCharacter) -> Option<FaceCharacter> {
-    match face {
-        FaceCharacter::Ace => None,
-        FaceCharacter::King => Some(FaceCharacter::Ace),
-        FaceCharacter::Queen => Some(FaceCharacter::King),
-        FaceCharacter::Jack => Some(FaceCharacter::Queen),
-    }
-}
-
-fn is_flush(hand: &Vec<&Card>) -> bool {
+fn is_flush(hand: &[&Card]) -> bool {
     let first_suit = &hand[0].suit;
-    for i in 1..4 {
-        if first_suit != &hand[i].suit {
-            return false;
-        }
-    }
-    true
+    hand.iter().all(|card| &card.suit == first_suit)
 }
 
 #[derive(Debug, Eq, Clone)]
@@ -448,6 +344,21 @@ pub struct Card {
     pub card_type: CardType,
 }
 
+impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Card {
+        Card {
+            suit,
+            card_type: rank.into(),
+        }
+    }
+
+    /// # Panics
+    /// If the card type isn't one found in a deck, like `CardType::Number { number: 1 }`.
+    pub fn rank(&self) -> Rank {
+        Rank::try_from(&self.card_type).expect("card type is not a valid rank")
+    }
+}
+
 impl Ord for Card {
     fn cmp(&self, other: &Self) -> Ordering {
         self.card_type.cmp(&other.card_type)
@@ -466,13 +377,21 @@ impl PartialEq for Card {
     }
 }
 
+/// Cards display as "[Ace Clubs]", or as "Ac" with the alternate flag, `{:#}`. The compact form
+/// is the same notation `Card::from_str` accepts.
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}{}", self.rank().symbol(), self.suit.symbol());
+        }
         write!(f, "[{} {:?}]", self.card_type, self.suit)
     }
 }
 
-#[derive(Debug, Eq, Hash, Clone, PartialOrd, PartialEq)]
+/// The original way of describing a card's rank, kept so existing code can keep building cards
+/// this way. It can hold values no card has, so prefer [`Rank`] and convert with `into` and
+/// `try_from`.
+#[derive(Debug, Eq, Hash, Clone, PartialEq)]
 pub enum CardType {
     Face { face_character: FaceCharacter },
     Number { number: u8 },
@@ -497,6 +416,12 @@ impl Ord for CardType {
     }
 }
 
+impl PartialOrd for CardType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for CardType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -506,6 +431,129 @@ impl fmt::Display for CardType {
     }
 }
 
+impl From<Rank> for CardType {
+    fn from(rank: Rank) -> Self {
+        match rank {
+            Rank::Jack => CardType::Face {
+                face_character: FaceCharacter::Jack,
+            },
+            Rank::Queen => CardType::Face {
+                face_character: FaceCharacter::Queen,
+            },
+            Rank::King => CardType::Face {
+                face_character: FaceCharacter::King,
+            },
+            Rank::Ace => CardType::Face {
+                face_character: FaceCharacter::Ace,
+            },
+            number => CardType::Number {
+                number: number.value(),
+            },
+        }
+    }
+}
+
+const RANKS: [Rank; 13] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
+
+/// The rank of a card, Two up to Ace. Every value is a card that can be in a deck.
+#[derive(Debug, EnumIter, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Two = 2,
+    Three = 3,
+    Four = 4,
+    Five = 5,
+    Six = 6,
+    Seven = 7,
+    Eight = 8,
+    Nine = 9,
+    Ten = 10,
+    Jack = 11,
+    Queen = 12,
+    King = 13,
+    Ace = 14,
+}
+
+impl Rank {
+    /// 2 for a Two up to 10 for a Ten, then 11 to 14 for Jack through Ace.
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_value(value: u8) -> Option<Rank> {
+        Rank::from_index(value.checked_sub(2)?)
+    }
+
+    /// 0 for a Two up to 12 for an Ace.
+    pub fn index(self) -> u8 {
+        self.value() - 2
+    }
+
+    pub fn from_index(index: u8) -> Option<Rank> {
+        RANKS.get(index as usize).copied()
+    }
+
+    /// Will return the rank one above, or None if this is an Ace
+    pub fn next(self) -> Option<Rank> {
+        Rank::from_value(self.value() + 1)
+    }
+
+    /// Will return the rank one below, or None if this is a Two
+    pub fn prev(self) -> Option<Rank> {
+        Rank::from_value(self.value() - 1)
+    }
+
+    /// The single character used for the rank in card notation, T for a Ten.
+    pub fn symbol(self) -> char {
+        match self {
+            Rank::Ten => 'T',
+            Rank::Jack => 'J',
+            Rank::Queen => 'Q',
+            Rank::King => 'K',
+            Rank::Ace => 'A',
+            number => (b'0' + number.value()) as char,
+        }
+    }
+}
+
+impl TryFrom<&CardType> for Rank {
+    type Error = &'static str;
+
+    fn try_from(card_type: &CardType) -> Result<Self, Self::Error> {
+        match card_type {
+            CardType::Face { face_character } => Ok(match face_character {
+                FaceCharacter::Jack => Rank::Jack,
+                FaceCharacter::Queen => Rank::Queen,
+                FaceCharacter::King => Rank::King,
+                FaceCharacter::Ace => Rank::Ace,
+            }),
+            CardType::Number { number } if (2..=10).contains(number) => {
+                Ok(Rank::from_value(*number).expect("numbers 2 to 10 are all ranks"))
+            }
+            CardType::Number { number: _ } => Err("card numbers must be between 2 and 10"),
+        }
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", CardType::from(*self))
+    }
+}
+
 #[derive(Debug, EnumIter, Clone, Copy, Eq, PartialEq)]
 pub enum Suit {
     Hearts,
@@ -514,6 +562,18 @@ pub enum Suit {
     Clubs,
 }
 
+impl Suit {
+    /// The single lowercase character used for the suit in card notation.
+    pub fn symbol(self) -> char {
+        match self {
+            Suit::Hearts => 'h',
+            Suit::Diamonds => 'd',
+            Suit::Spades => 's',
+            Suit::Clubs => 'c',
+        }
+    }
+}
+
 #[derive(Debug, EnumIter, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
 pub enum FaceCharacter {
     Jack,
@@ -522,40 +582,82 @@ pub enum FaceCharacter {
     Ace,
 }
 
-#[derive(Debug)]
-struct Player {
-    // could potentially make this an enum
-    cards: Vec<Card>, // this could also be an array of size 5
-    chips: Vec<Chip>,
-}
-
-#[derive(Debug)]
-enum Round {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Round {
     PreFlop,
     Flop,
     Turn,
     River,
 }
 
-// TODO: lowest to highest
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
 pub enum HandRank {
-    RoyalFlush = 10,
-    StraightFlush = 9,
-    FourOfAKind = 8,
-    FullHouse = 7,
-    Flush = 6,
-    Straight = 5,
-    ThreeOfAKind = 4,
-    TwoPair = 3,
-    Pair = 2,
     HighCard = 1,
+    Pair = 2,
+    TwoPair = 3,
+    ThreeOfAKind = 4,
+    Straight = 5,
+    Flush = 6,
+    FullHouse = 7,
+    FourOfAKind = 8,
+    StraightFlush = 9,
+    RoyalFlush = 10,
+}
+
+/// A hand rank together with the card ranks that break ties between hands of that rank, most
+/// significant first. Ordering compares the rank first and then the kickers in turn, so the
+/// greater value always wins a showdown and equal values split the pot.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+pub struct HandValue {
+    pub rank: HandRank,
+    pub kickers: Vec<Rank>,
+}
+
+impl HandValue {
+    pub fn new(rank: HandRank, kickers: Vec<Rank>) -> HandValue {
+        HandValue { rank, kickers }
+    }
 }
 
-#[derive(Debug)]
+/// The five cards picked out of a player's hole cards and the board, in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BestHand {
+    pub cards: Vec<Card>,
+    pub value: HandValue,
+}
+
+/// The denominations a stack can be shown in. Amounts are all kept as plain numbers of chips,
+/// this is only for display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Chip {
     One,
     Five,
     TwentyFive,
     Fifty,
 }
+
+impl Chip {
+    pub fn value(&self) -> u32 {
+        match self {
+            Chip::One => 1,
+            Chip::Five => 5,
+            Chip::TwentyFive => 25,
+            Chip::Fifty => 50,
+        }
+    }
+
+    /// Makes change for the amount using as few chips as possible, largest first.
+    /// # Example
+    /// 57 -> [Fifty, Five, One, One]
+    pub fn breakdown(amount: u32) -> Vec<Chip> {
+        let mut remaining = amount;
+        let mut chips = vec![];
+        for chip in [Chip::Fifty, Chip::TwentyFive, Chip::Five, Chip::One] {
+            while remaining >= chip.value() {
+                chips.push(chip);
+                remaining -= chip.value();
+            }
+        }
+        chips
+    }
+}
@@ -18,9 +18,15 @@ fn main() {
     println!("You've selected {} players.", config.number_of_players);
 
     // start game
-    let result = rust_poker::play_game(config.number_of_players);
+    let result = rust_poker::play_game(&config);
     match result {
-        Ok(_) => println!("thanks for playing"),
+        Ok(stacks) => {
+            for (i, stack) in stacks.iter().enumerate() {
+                println!("player {} finishes with {}", i + 1, stack);
+            }
+            println!("GAME OVER");
+            println!("thanks for playing")
+        }
         Err(msg) => println!("{}", msg),
     }
 }
@@ -0,0 +1,212 @@
+//! Parsing cards from the usual two character notation, rank then suit: "As", "Td", "2c".
+//!
+//! Ranks are 2-9, T, J, Q, K and A ("10" is also accepted for a Ten) and suits are s, h, d and c.
+//! Either case is accepted for both. Several cards can be written back to back or separated by
+//! whitespace, so "AsKd" and "As Kd Qh" both work.
+
+use crate::{Card, CardSet, PackedCard, Rank, Suit};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Why some card notation couldn't be parsed. Positions count characters from the start of the
+/// input, starting at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCardError {
+    Empty,
+    InvalidRank { position: usize, found: char },
+    InvalidSuit { position: usize, found: char },
+    MissingSuit { position: usize },
+    DuplicateCard { position: usize, card: Card },
+    UnexpectedCharacter { position: usize, found: char },
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseCardError::Empty => write!(f, "expected a card but found nothing"),
+            ParseCardError::InvalidRank { position, found } => write!(
+                f,
+                "invalid rank '{}' at position {}, expected one of 23456789TJQKA",
+                found, position
+            ),
+            ParseCardError::InvalidSuit { position, found } => write!(
+                f,
+                "invalid suit '{}' at position {}, expected one of shdc",
+                found, position
+            ),
+            ParseCardError::MissingSuit { position } => {
+                write!(f, "missing suit at position {}", position)
+            }
+            ParseCardError::DuplicateCard { position, card } => {
+                write!(
+                    f,
+                    "{:#} at position {} appears more than once",
+                    card, position
+                )
+            }
+            ParseCardError::UnexpectedCharacter { position, found } => write!(
+                f,
+                "unexpected '{}' at position {} after the card",
+                found, position
+            ),
+        }
+    }
+}
+
+impl Error for ParseCardError {}
+
+/// Parses any number of cards, either written back to back or separated by whitespace. An empty
+/// string is no cards, which is a valid board before the flop.
+/// # Example
+/// "As Kd Qh" -> Ace Spades, King Diamonds, Queen Hearts
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseCardError> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut cards = vec![];
+    let mut seen = CardSet::new();
+
+    let mut position = 0;
+    while position < chars.len() {
+        if chars[position].is_whitespace() {
+            position += 1;
+            continue;
+        }
+
+        let (card, next) = parse_card_at(&chars, position)?;
+        if !seen.insert(PackedCard::new(card.rank(), card.suit)) {
+            return Err(ParseCardError::DuplicateCard { position, card });
+        }
+        cards.push(card);
+        position = next;
+    }
+
+    Ok(cards)
+}
+
+/// Parses the card starting at `position`, returning it and the position just after it.
+fn parse_card_at(chars: &[char], position: usize) -> Result<(Card, usize), ParseCardError> {
+    let (rank, suit_position) = parse_rank_at(chars, position)?;
+    let suit = match chars.get(suit_position) {
+        Some(found) => parse_suit(*found).ok_or(ParseCardError::InvalidSuit {
+            position: suit_position,
+            found: *found,
+        })?,
+        None => {
+            return Err(ParseCardError::MissingSuit {
+                position: suit_position,
+            })
+        }
+    };
+
+    Ok((Card::new(rank, suit), suit_position + 1))
+}
+
+fn parse_rank_at(chars: &[char], position: usize) -> Result<(Rank, usize), ParseCardError> {
+    let found = match chars.get(position) {
+        Some(found) => *found,
+        None => return Err(ParseCardError::Empty),
+    };
+
+    if found == '1' && chars.get(position + 1) == Some(&'0') {
+        return Ok((Rank::Ten, position + 2));
+    }
+
+    let rank = parse_rank(found).ok_or(ParseCardError::InvalidRank { position, found })?;
+    Ok((rank, position + 1))
+}
+
+/// The rank for a single rank character, so not "10".
+pub(crate) fn parse_rank(found: char) -> Option<Rank> {
+    match found.to_ascii_uppercase() {
+        'T' => Some(Rank::Ten),
+        'J' => Some(Rank::Jack),
+        'Q' => Some(Rank::Queen),
+        'K' => Some(Rank::King),
+        'A' => Some(Rank::Ace),
+        digit => digit
+            .to_digit(10)
+            .and_then(|value| Rank::from_value(value as u8))
+            .filter(|rank| rank < &Rank::Ten),
+    }
+}
+
+pub(crate) fn parse_suit(found: char) -> Option<Suit> {
+    match found.to_ascii_lowercase() {
+        's' => Some(Suit::Spades),
+        'h' => Some(Suit::Hearts),
+        'd' => Some(Suit::Diamonds),
+        'c' => Some(Suit::Clubs),
+        _ => None,
+    }
+}
+
+/// Skips leading whitespace and returns the position of the first character, or an error if
+/// there isn't one.
+fn first_character(chars: &[char]) -> Result<usize, ParseCardError> {
+    chars
+        .iter()
+        .position(|c| !c.is_whitespace())
+        .ok_or(ParseCardError::Empty)
+}
+
+/// Errors if anything other than whitespace follows `position`.
+fn expect_end(chars: &[char], position: usize) -> Result<(), ParseCardError> {
+    match chars
+        .iter()
+        .enumerate()
+        .skip(position)
+        .find(|(_, c)| !c.is_whitespace())
+    {
+        Some((position, found)) => Err(ParseCardError::UnexpectedCharacter {
+            position,
+            found: *found,
+        }),
+        None => Ok(()),
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        let (card, next) = parse_card_at(&chars, first_character(&chars)?)?;
+        expect_end(&chars, next)?;
+        Ok(card)
+    }
+}
+
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        let (rank, next) = parse_rank_at(&chars, first_character(&chars)?)?;
+        expect_end(&chars, next)?;
+        Ok(rank)
+    }
+}
+
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        let position = first_character(&chars)?;
+        let found = chars[position];
+        let suit = parse_suit(found).ok_or(ParseCardError::InvalidSuit { position, found })?;
+        expect_end(&chars, position + 1)?;
+        Ok(suit)
+    }
+}
+
+impl FromStr for CardSet {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(parse_cards(s)?
+            .iter()
+            .map(|card| PackedCard::new(card.rank(), card.suit))
+            .collect())
+    }
+}
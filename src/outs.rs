@@ -0,0 +1,270 @@
+//! What the cards still to come can do for a hand on the flop or turn: which cards improve it,
+//! what it's drawing to and how likely it is to get there by the river.
+
+use crate::evaluator::{set_strength, strength_rank};
+use crate::{Card, CardError, CardSet, HandRank, PackedCard, Rank, Suit};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use strum::IntoEnumIterator;
+
+/// Why a hand and board can't be analysed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutsError {
+    /// The hand has to be exactly two hole cards.
+    WrongNumberOfCards { count: usize },
+    /// Only the flop or the turn has cards still to come that can be analysed.
+    WrongBoardSize { count: usize },
+    /// A card is used twice or isn't a card at all.
+    Card(CardError),
+}
+
+impl fmt::Display for OutsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OutsError::WrongNumberOfCards { count } => {
+                write!(f, "the hand has {} cards, it needs exactly two", count)
+            }
+            OutsError::WrongBoardSize { count } => write!(
+                f,
+                "the board has {} cards, it needs three or four to have outs",
+                count
+            ),
+            OutsError::Card(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for OutsError {}
+
+impl From<CardError> for OutsError {
+    fn from(err: CardError) -> Self {
+        OutsError::Card(err)
+    }
+}
+
+/// A draw that uses at least one of the hole cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Draw {
+    /// Four to a flush.
+    FlushDraw,
+    /// Two different ranks complete a straight, either end of four in a row or a double gutter.
+    OpenEnder,
+    /// Only one rank completes a straight.
+    Gutshot,
+    /// Three to a flush on the flop, needing both the turn and the river.
+    BackdoorFlushDraw,
+    /// A straight on the flop that needs both the turn and the river.
+    BackdoorStraightDraw,
+}
+
+impl fmt::Display for Draw {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Draw::FlushDraw => "flush draw",
+            Draw::OpenEnder => "open-ended straight draw",
+            Draw::Gutshot => "gutshot",
+            Draw::BackdoorFlushDraw => "backdoor flush draw",
+            Draw::BackdoorStraightDraw => "backdoor straight draw",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The ways to end up with one hand rank better than the current one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outs {
+    pub rank: HandRank,
+    /// The next cards that make exactly this rank, empty if it needs more than one card.
+    pub cards: Vec<Card>,
+    /// Chance of having this rank or better once the river is dealt.
+    pub by_river_percent: f64,
+}
+
+/// Where a hand stands on the flop or turn.
+/// # Example
+/// Ah 5h on Kh 8h 2c -> Ace high with a flush draw, 9 outs to a Flush
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrawAnalysis {
+    pub current: HandRank,
+    /// Every better rank the hand can still reach, lowest first.
+    pub outs: Vec<Outs>,
+    pub draws: Vec<Draw>,
+    /// Chance of any better rank than `current` once the river is dealt, counted the same way as
+    /// the outs.
+    pub improve_by_river_percent: f64,
+}
+
+impl DrawAnalysis {
+    /// How many of the unseen cards improve the hand straight away.
+    pub fn out_count(&self) -> usize {
+        self.outs.iter().map(|outs| outs.cards.len()).sum()
+    }
+}
+
+/// Looks at every card that could come next and every way the board could finish. A card or
+/// runout only counts when the hole cards are part of the improvement, not when it just makes
+/// the board better for everyone. Cards the player can't see are all counted as unseen, so
+/// other players' hole cards aren't taken out.
+pub fn analyse_outs(hole_cards: &[Card], board: &[Card]) -> Result<DrawAnalysis, OutsError> {
+    if hole_cards.len() != 2 {
+        return Err(OutsError::WrongNumberOfCards {
+            count: hole_cards.len(),
+        });
+    }
+    if board.len() != 3 && board.len() != 4 {
+        return Err(OutsError::WrongBoardSize { count: board.len() });
+    }
+
+    let mut known = CardSet::new();
+    let mut claim_all = |cards: &[Card]| -> Result<CardSet, CardError> {
+        cards.iter().map(|card| known.claim(card)).collect()
+    };
+    let hole = claim_all(hole_cards)?;
+    let board_set = claim_all(board)?;
+    let unseen: Vec<PackedCard> = (!known).iter().collect();
+    let current = strength_rank(set_strength(known));
+
+    // the cards that improve straight away, and how often each rank is what the hand finishes
+    // with over every way the board can be completed
+    let mut reached: BTreeMap<HandRank, (Vec<Card>, u64)> = BTreeMap::new();
+    for card in &unseen {
+        if let Some(rank) = improvement(hole, board_set | CardSet::from(*card), current) {
+            reached.entry(rank).or_default().0.push(Card::from(*card));
+        }
+    }
+
+    let mut runouts = 0;
+    for runout in unseen.iter().combinations(5 - board.len()) {
+        let board = runout
+            .into_iter()
+            .fold(board_set, |set, card| set | (*card).into());
+        if let Some(rank) = improvement(hole, board, current) {
+            reached.entry(rank).or_default().1 += 1;
+        }
+        runouts += 1;
+    }
+
+    let percent = |count: u64| 100.0 * count as f64 / runouts as f64;
+    let outs = reached
+        .iter()
+        .map(|(rank, (cards, _))| Outs {
+            rank: *rank,
+            cards: cards.clone(),
+            by_river_percent: percent(reached.range(rank..).map(|(_, (_, n))| n).sum()),
+        })
+        .collect();
+
+    Ok(DrawAnalysis {
+        current,
+        outs,
+        draws: draws(hole, board_set, current),
+        improve_by_river_percent: percent(reached.values().map(|(_, n)| n).sum()),
+    })
+}
+
+/// The rank the hand improves to on the new board, if it's better than `current` because of the
+/// hole cards. Improving only because the board got better, like the board pairing, doesn't
+/// count: the hand has to reach a better rank than the board makes by itself, or make a better
+/// straight or flush than the one on the board.
+fn improvement(hole: CardSet, board: CardSet, current: HandRank) -> Option<HandRank> {
+    let strength = set_strength(hole | board);
+    let rank = strength_rank(strength);
+    if rank <= current {
+        return None;
+    }
+
+    let (board_rank, board_strength) = board_hand(board);
+    let plays = match board_strength {
+        _ if rank > board_rank => true,
+        // with the same rank the hole cards only matter if they're part of the made hand,
+        // not a kicker
+        Some(board_strength) if rank == board_rank => {
+            let made = !matches!(
+                rank,
+                HandRank::HighCard
+                    | HandRank::Pair
+                    | HandRank::TwoPair
+                    | HandRank::ThreeOfAKind
+                    | HandRank::FourOfAKind
+            );
+            made && strength > board_strength
+        }
+        _ => false,
+    };
+    plays.then_some(rank)
+}
+
+/// The rank the board makes on its own, and its strength once it has five cards. Short of five
+/// cards only pairs, trips and quads are possible.
+fn board_hand(board: CardSet) -> (HandRank, Option<u16>) {
+    if board.len() >= 5 {
+        let strength = set_strength(board);
+        return (strength_rank(strength), Some(strength));
+    }
+
+    let mut sets: Vec<usize> = Rank::iter()
+        .map(|rank| board.rank_count(rank))
+        .filter(|count| *count > 1)
+        .collect();
+    sets.sort_unstable_by(|a, b| b.cmp(a));
+    let rank = match sets.as_slice() {
+        [4, ..] => HandRank::FourOfAKind,
+        [3, ..] => HandRank::ThreeOfAKind,
+        [2, 2, ..] => HandRank::TwoPair,
+        [2] => HandRank::Pair,
+        _ => HandRank::HighCard,
+    };
+    (rank, None)
+}
+
+fn draws(hole: CardSet, board: CardSet, current: HandRank) -> Vec<Draw> {
+    let known = hole | board;
+    let on_flop = board.len() == 3;
+    let mut draws = vec![];
+
+    if current < HandRank::Flush {
+        for suit in Suit::iter() {
+            let suited = known & CardSet::suit(suit);
+            if (hole & suited).is_empty() {
+                continue;
+            }
+            match suited.len() {
+                4 => draws.push(Draw::FlushDraw),
+                3 if on_flop => draws.push(Draw::BackdoorFlushDraw),
+                _ => {}
+            }
+        }
+    }
+
+    if current < HandRank::Straight {
+        let mask = known.rank_mask();
+        let board_mask = board.rank_mask();
+        // a straight the board makes on its own isn't the player's draw
+        let completes = |extra: u16| is_straight(mask | extra) && !is_straight(board_mask | extra);
+
+        let completing = (0..13).filter(|rank| completes(1 << rank)).count();
+        match completing {
+            0 => {
+                let backdoor = (0..13)
+                    .flat_map(|low| (low + 1..13).map(move |high| (1 << low) | (1 << high)))
+                    .any(completes);
+                if on_flop && backdoor {
+                    draws.push(Draw::BackdoorStraightDraw);
+                }
+            }
+            1 => draws.push(Draw::Gutshot),
+            _ => draws.push(Draw::OpenEnder),
+        }
+    }
+
+    draws
+}
+
+/// True if the ranks, bit 0 for a Two up to bit 12 for an Ace, include five in a row. The Ace
+/// also counts low for the wheel.
+fn is_straight(ranks: u16) -> bool {
+    const WHEEL: u16 = 0b1_0000_0000_1111;
+    ranks & WHEEL == WHEEL || (0..=8).any(|low| ranks & (0b11111 << low) == 0b11111 << low)
+}
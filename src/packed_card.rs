@@ -0,0 +1,102 @@
+use crate::{Card, CardType, Rank, Suit};
+use std::fmt;
+
+const SUITS: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Spades, Suit::Clubs];
+
+/// A card packed into a single byte, the [`Rank::index`] (0 for a Two up to 12 for an Ace) in the
+/// upper bits and the suit in the bottom two. The byte is also the card's index in the deck,
+/// 0 to 51, so it can be used directly as a bit position in a `u64`.
+///
+/// Packed cards order by rank and then by suit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedCard(u8);
+
+impl PackedCard {
+    /// Number of distinct cards, every valid packed card is below this.
+    pub const COUNT: u8 = 52;
+
+    pub fn new(rank: Rank, suit: Suit) -> PackedCard {
+        PackedCard(rank.index() << 2 | suit_index(suit))
+    }
+
+    /// The card's position in the deck, between 0 and 51.
+    pub fn index(self) -> u8 {
+        self.0
+    }
+
+    pub fn rank(self) -> Rank {
+        Rank::from_index(self.rank_index()).expect("packed cards only hold valid ranks")
+    }
+
+    /// 0 for a Two up to 12 for an Ace, see [`Rank::index`].
+    pub fn rank_index(self) -> u8 {
+        self.0 >> 2
+    }
+
+    pub fn suit(self) -> Suit {
+        SUITS[(self.0 & 0b11) as usize]
+    }
+
+    pub fn card_type(self) -> CardType {
+        self.rank().into()
+    }
+
+    /// The card as a single set bit, see [`PackedCard::index`].
+    pub fn bit(self) -> u64 {
+        1 << self.0
+    }
+
+    /// Iterates over all 52 cards in index order.
+    pub fn all() -> impl Iterator<Item = PackedCard> {
+        (0..PackedCard::COUNT).map(PackedCard)
+    }
+}
+
+fn suit_index(suit: Suit) -> u8 {
+    match suit {
+        Suit::Hearts => 0,
+        Suit::Diamonds => 1,
+        Suit::Spades => 2,
+        Suit::Clubs => 3,
+    }
+}
+
+impl TryFrom<u8> for PackedCard {
+    type Error = &'static str;
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        if index >= PackedCard::COUNT {
+            return Err("card index must be below 52");
+        }
+        Ok(PackedCard(index))
+    }
+}
+
+impl From<PackedCard> for u8 {
+    fn from(card: PackedCard) -> Self {
+        card.0
+    }
+}
+
+impl TryFrom<&Card> for PackedCard {
+    type Error = &'static str;
+
+    fn try_from(card: &Card) -> Result<Self, Self::Error> {
+        Ok(PackedCard::new(Rank::try_from(&card.card_type)?, card.suit))
+    }
+}
+
+impl From<PackedCard> for Card {
+    fn from(card: PackedCard) -> Self {
+        Card {
+            suit: card.suit(),
+            card_type: card.card_type(),
+        }
+    }
+}
+
+impl fmt::Display for PackedCard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&Card::from(*self), f)
+    }
+}
@@ -0,0 +1,79 @@
+/// Where the button and blinds are for a hand, as seat indexes. Seats are passed around as a
+/// slice with an entry per seat saying whether it still has chips to play with.
+///
+/// The big blind moves on to the next player with chips every hand, the small blind goes to last
+/// hand's big blind and the button to last hand's small blind. If those players have busted the
+/// small blind isn't posted and the button sits in front of an empty seat, a dead button, so
+/// nobody posts the big blind twice in a row or gets to skip it. Heads up the button posts the
+/// small blind and the other player the big blind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TablePositions {
+    pub button: usize,
+    pub small_blind: usize,
+    pub big_blind: usize,
+}
+
+impl TablePositions {
+    /// Positions for the first hand of a session with the button on the given seat, or `None`
+    /// if fewer than two seats have chips.
+    pub fn first_hand(button: usize, active: &[bool]) -> Option<TablePositions> {
+        if active.iter().filter(|a| **a).count() < 2 {
+            return None;
+        }
+
+        let button = if active[button] {
+            button
+        } else {
+            next_active(button, active)
+        };
+        if is_heads_up(active) {
+            return Some(TablePositions {
+                button,
+                small_blind: button,
+                big_blind: next_active(button, active),
+            });
+        }
+
+        let small_blind = next_active(button, active);
+        Some(TablePositions {
+            button,
+            small_blind,
+            big_blind: next_active(small_blind, active),
+        })
+    }
+
+    /// Moves everything on for the next hand, or `None` once fewer than two seats have chips.
+    pub fn next_hand(&self, active: &[bool]) -> Option<TablePositions> {
+        if active.iter().filter(|a| **a).count() < 2 {
+            return None;
+        }
+
+        let big_blind = next_active(self.big_blind, active);
+        if is_heads_up(active) {
+            let button = next_active(big_blind, active);
+            return Some(TablePositions {
+                button,
+                small_blind: button,
+                big_blind,
+            });
+        }
+
+        Some(TablePositions {
+            button: self.small_blind,
+            small_blind: self.big_blind,
+            big_blind,
+        })
+    }
+}
+
+fn is_heads_up(active: &[bool]) -> bool {
+    active.iter().filter(|a| **a).count() == 2
+}
+
+/// The first seat after `seat` going round the table that has chips.
+fn next_active(seat: usize, active: &[bool]) -> usize {
+    (1..=active.len())
+        .map(|n| (seat + n) % active.len())
+        .find(|s| active[*s])
+        .expect("at least one seat has chips")
+}
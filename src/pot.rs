@@ -0,0 +1,214 @@
+use crate::{Card, HandValue, Suit};
+
+/// A pot and the seats that can win it, the players who put chips in at least up to this pot's
+/// level and haven't folded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pot {
+    pub amount: u32,
+    pub eligible: Vec<usize>,
+}
+
+/// Who won a pot and how much each of them took from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PotAward {
+    pub pot: Pot,
+    pub winners: Vec<(usize, u32)>,
+}
+
+/// Decides who gets the chips left over when a pot doesn't split evenly between tied hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OddChipRule {
+    /// One chip each to the tied players in order round the table starting left of the button.
+    #[default]
+    LeftOfButton,
+    /// One chip each to the tied players in order of the highest card in their hand, with suits
+    /// breaking ties between cards of the same rank, spades then hearts, diamonds and clubs.
+    HighestCardBySuit,
+}
+
+impl OddChipRule {
+    /// Every seat in the order odd chips are handed out. `hole_cards` has an entry per seat and
+    /// is only needed for [`OddChipRule::HighestCardBySuit`].
+    pub fn seat_order(self, button: usize, hole_cards: &[Vec<Card>]) -> Vec<usize> {
+        let num_seats = hole_cards.len();
+        let left_of_button: Vec<usize> =
+            (1..=num_seats).map(|n| (button + n) % num_seats).collect();
+
+        match self {
+            OddChipRule::LeftOfButton => left_of_button,
+            OddChipRule::HighestCardBySuit => {
+                let mut seats = left_of_button;
+                // seats without cards go last
+                seats.sort_by_key(|seat| std::cmp::Reverse(highest_card(&hole_cards[*seat])));
+                seats
+            }
+        }
+    }
+}
+
+/// The highest card as (rank, suit) so it can be compared directly.
+fn highest_card(cards: &[Card]) -> Option<(u8, u8)> {
+    cards
+        .iter()
+        .map(|card| (card.rank().value(), suit_order(card.suit)))
+        .max()
+}
+
+fn suit_order(suit: Suit) -> u8 {
+    match suit {
+        Suit::Spades => 4,
+        Suit::Hearts => 3,
+        Suit::Diamonds => 2,
+        Suit::Clubs => 1,
+    }
+}
+
+/// Keeps track of what every seat has put in over a hand so the chips can be split into a main
+/// pot and side pots once players are all in for different amounts.
+#[derive(Debug, Clone)]
+pub struct PotManager {
+    contributions: Vec<u32>,
+    folded: Vec<bool>,
+}
+
+impl PotManager {
+    pub fn new(num_seats: usize) -> PotManager {
+        PotManager {
+            contributions: vec![0; num_seats],
+            folded: vec![false; num_seats],
+        }
+    }
+
+    pub fn contribute(&mut self, seat: usize, amount: u32) {
+        self.contributions[seat] += amount;
+    }
+
+    /// A folded seat's chips stay in the pots but it can't win any of them.
+    pub fn fold(&mut self, seat: usize) {
+        self.folded[seat] = true;
+    }
+
+    /// Everything put in by the seat this hand.
+    pub fn contribution(&self, seat: usize) -> u32 {
+        self.contributions[seat]
+    }
+
+    pub fn total(&self) -> u32 {
+        self.contributions.iter().sum()
+    }
+
+    /// Splits the contributions into pots, main pot first and then each side pot in the order
+    /// players went all in. Every level a live player stopped putting chips in at starts a new
+    /// pot, so a pot's eligible seats are exactly the live players that covered it.
+    pub fn pots(&self) -> Vec<Pot> {
+        let mut levels: Vec<u32> = self
+            .contributions
+            .iter()
+            .zip(self.folded.iter())
+            .filter(|(amount, folded)| !**folded && **amount > 0)
+            .map(|(amount, _)| *amount)
+            .collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut pots: Vec<Pot> = vec![];
+        let mut previous = 0;
+        for level in levels {
+            let amount = self
+                .contributions
+                .iter()
+                .map(|c| (*c).min(level) - (*c).min(previous))
+                .sum();
+            let eligible: Vec<usize> = (0..self.contributions.len())
+                .filter(|seat| !self.folded[*seat] && self.contributions[*seat] >= level)
+                .collect();
+            previous = level;
+
+            match pots.last_mut() {
+                Some(last) if last.eligible == eligible => last.amount += amount,
+                _ => pots.push(Pot { amount, eligible }),
+            }
+        }
+
+        // chips from folded players above everything a live player put in, can only happen if
+        // everyone left folded, go with the last pot
+        let unallocated = self.total() - pots.iter().map(|pot| pot.amount).sum::<u32>();
+        if unallocated > 0 {
+            match pots.last_mut() {
+                Some(last) => last.amount += unallocated,
+                None => pots.push(Pot {
+                    amount: unallocated,
+                    eligible: (0..self.folded.len())
+                        .filter(|seat| !self.folded[*seat])
+                        .collect(),
+                }),
+            }
+        }
+
+        pots
+    }
+
+    /// Awards each pot to the best hand among its eligible seats, splitting it evenly between
+    /// tied hands. `hands` has an entry per seat, `None` for any seat without a hand to show. A
+    /// pot with a single eligible seat goes to it without looking at the hands. Chips that
+    /// don't divide evenly go one at a time to the tied seats that come first in
+    /// `odd_chip_order`, see [`OddChipRule::seat_order`].
+    pub fn award(&self, hands: &[Option<HandValue>], odd_chip_order: &[usize]) -> Vec<PotAward> {
+        self.pots()
+            .into_iter()
+            .map(|pot| {
+                let winners = pot_winners(&pot, hands);
+                let winners = split(pot.amount, &winners, odd_chip_order);
+                PotAward { pot, winners }
+            })
+            .collect()
+    }
+
+    /// Total each seat won across all the awards.
+    pub fn payouts(&self, awards: &[PotAward]) -> Vec<u32> {
+        let mut payouts = vec![0; self.contributions.len()];
+        for award in awards {
+            for (seat, amount) in &award.winners {
+                payouts[*seat] += amount;
+            }
+        }
+        payouts
+    }
+}
+
+fn pot_winners(pot: &Pot, hands: &[Option<HandValue>]) -> Vec<usize> {
+    if pot.eligible.len() == 1 {
+        return pot.eligible.clone();
+    }
+
+    let best = pot
+        .eligible
+        .iter()
+        .filter_map(|seat| hands[*seat].as_ref())
+        .max();
+    pot.eligible
+        .iter()
+        .copied()
+        .filter(|seat| best.is_some() && hands[*seat].as_ref() == best)
+        .collect()
+}
+
+/// Splits the amount evenly between the winners, listed in seat order.
+fn split(amount: u32, winners: &[usize], odd_chip_order: &[usize]) -> Vec<(usize, u32)> {
+    if winners.is_empty() {
+        return vec![];
+    }
+
+    let share = amount / winners.len() as u32;
+    let odd_chips = (amount % winners.len() as u32) as usize;
+    let lucky: Vec<usize> = odd_chip_order
+        .iter()
+        .copied()
+        .filter(|seat| winners.contains(seat))
+        .take(odd_chips)
+        .collect();
+    winners
+        .iter()
+        .map(|seat| (*seat, share + u32::from(lucky.contains(seat))))
+        .collect()
+}
@@ -0,0 +1,500 @@
+//! Ranges of starting hands in the usual shorthand, like "TT+, AKs, AQo-AJo, 76s-54s".
+//!
+//! Each comma separated part is one of:
+//! - a pair, "TT", every pair from there up, "TT+", or a run of pairs, "TT-77"
+//! - two ranks for all 16 ways to hold them, "AK", just the 4 suited ones, "AKs", or the 12
+//!   offsuit ones, "AKo"
+//! - those with the kicker going up to just under the top card, "ATs+" for ATs, AJs, AQs and AKs
+//! - a run with the same top card, "AQo-AJo", or with both cards stepping down together,
+//!   "76s-54s" for 76s, 65s and 54s
+//! - exact hole cards, "AsKs"
+//!
+//! Any part can end with a weight for how often the hands are played, "AA:0.5", which has to be
+//! more than 0 and at most 1. A hand in more than one part keeps the weight from the last one.
+
+use crate::notation::{parse_rank, parse_suit};
+use crate::{Card, CardError, CardSet, PackedCard, Rank, Suit};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+/// Why a range couldn't be parsed. Positions count characters from the start of the whole
+/// range, starting at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRangeError {
+    /// Nothing between two commas, or at the start or end of the range.
+    MissingHand {
+        position: usize,
+    },
+    /// The hand stops before it's complete, like "A" or "AsK".
+    UnexpectedEnd {
+        position: usize,
+    },
+    InvalidRank {
+        position: usize,
+        found: char,
+    },
+    InvalidSuit {
+        position: usize,
+        found: char,
+    },
+    /// Pairs are always offsuit, so "AAs" and "AAo" aren't hands.
+    SuitedPair {
+        position: usize,
+    },
+    SameCard {
+        position: usize,
+        card: Card,
+    },
+    /// The two ends of a run don't describe a run, like "AKs-QJo" or "AK-T8".
+    InvalidSpan {
+        position: usize,
+    },
+    InvalidWeight {
+        position: usize,
+    },
+    UnexpectedCharacter {
+        position: usize,
+        found: char,
+    },
+}
+
+impl fmt::Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseRangeError::MissingHand { position } => {
+                write!(f, "expected a hand at position {}", position)
+            }
+            ParseRangeError::UnexpectedEnd { position } => {
+                write!(f, "hand ends early at position {}", position)
+            }
+            ParseRangeError::InvalidRank { position, found } => write!(
+                f,
+                "invalid rank '{}' at position {}, expected one of 23456789TJQKA",
+                found, position
+            ),
+            ParseRangeError::InvalidSuit { position, found } => write!(
+                f,
+                "invalid suit '{}' at position {}, expected one of shdc",
+                found, position
+            ),
+            ParseRangeError::SuitedPair { position } => write!(
+                f,
+                "pair at position {} can't be suited or offsuit",
+                position
+            ),
+            ParseRangeError::SameCard { position, card } => {
+                write!(f, "{:#} at position {} is used twice", card, position)
+            }
+            ParseRangeError::InvalidSpan { position } => write!(
+                f,
+                "the hands either side of the '-' at position {} don't make a run",
+                position
+            ),
+            ParseRangeError::InvalidWeight { position } => write!(
+                f,
+                "invalid weight at position {}, expected a number more than 0 and at most 1",
+                position
+            ),
+            ParseRangeError::UnexpectedCharacter { position, found } => {
+                write!(f, "unexpected '{}' at position {}", found, position)
+            }
+        }
+    }
+}
+
+impl Error for ParseRangeError {}
+
+/// Why hole cards couldn't be added to a range with [`Range::add`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeError {
+    /// The same card twice, or a card that isn't in the deck.
+    Card(CardError),
+    /// Weights have to be more than 0 and at most 1.
+    InvalidWeight { weight: f64 },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeError::Card(err) => write!(f, "{}", err),
+            RangeError::InvalidWeight { weight } => write!(
+                f,
+                "invalid weight {}, expected a number more than 0 and at most 1",
+                weight
+            ),
+        }
+    }
+}
+
+impl Error for RangeError {}
+
+impl From<CardError> for RangeError {
+    fn from(err: CardError) -> Self {
+        RangeError::Card(err)
+    }
+}
+
+/// One way to hold two hole cards, and how often it's in the range, 1.0 being always.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Combo {
+    /// Higher rank first.
+    pub cards: [Card; 2],
+    pub weight: f64,
+}
+
+impl Combo {
+    pub fn card_set(&self) -> CardSet {
+        self.cards
+            .iter()
+            .map(|card| PackedCard::new(card.rank(), card.suit))
+            .collect()
+    }
+}
+
+/// Combos display in card notation, "AsKs", with the weight after a colon if it isn't 1.
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#}{:#}", self.cards[0], self.cards[1])?;
+        if self.weight != 1.0 {
+            write!(f, ":{}", self.weight)?;
+        }
+        Ok(())
+    }
+}
+
+/// A set of hole cards, each with a weight. Usually parsed from shorthand like "TT+, AKs:0.5".
+/// # Example
+/// "QQ+, AKs" -> 22 combos, the 18 ways to hold a pair of Queens, Kings or Aces and 4 suited
+/// Ace Kings
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Range {
+    combos: Vec<Combo>,
+}
+
+impl Range {
+    pub fn new() -> Range {
+        Range::default()
+    }
+
+    /// Adds the hole cards, or changes their weight if they're already in the range. The cards
+    /// have to be two different cards from the deck and the weight more than 0 and at most 1.
+    pub fn add(&mut self, cards: [Card; 2], weight: f64) -> Result<(), RangeError> {
+        let mut set = CardSet::new();
+        for card in &cards {
+            set.claim(card)?;
+        }
+        if !is_valid_weight(weight) {
+            return Err(RangeError::InvalidWeight { weight });
+        }
+
+        self.insert(cards, weight);
+        Ok(())
+    }
+
+    /// Adds hole cards that are already known to be valid.
+    fn insert(&mut self, cards: [Card; 2], weight: f64) {
+        let mut combo = Combo { cards, weight };
+        if combo.cards[0] < combo.cards[1] {
+            combo.cards.swap(0, 1);
+        }
+
+        let set = combo.card_set();
+        match self.combos.iter_mut().find(|c| c.card_set() == set) {
+            Some(existing) => existing.weight = weight,
+            None => self.combos.push(combo),
+        }
+    }
+
+    /// Every combo in the order it was added.
+    pub fn combos(&self) -> &[Combo] {
+        &self.combos
+    }
+
+    /// How many combos are in the range whatever their weights.
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// How many combos the range is worth once weights are counted, so "AA:0.5" is 3.
+    pub fn weighted_len(&self) -> f64 {
+        self.combos.iter().map(|combo| combo.weight).sum()
+    }
+
+    /// How often the hole cards are in the range, 0 if they never are. The order of the cards
+    /// doesn't matter.
+    pub fn weight(&self, cards: &[Card; 2]) -> f64 {
+        let set = match CardSet::try_from(&cards[..]) {
+            Ok(set) => set,
+            Err(_) => return 0.0,
+        };
+        self.combos
+            .iter()
+            .find(|combo| combo.card_set() == set)
+            .map_or(0.0, |combo| combo.weight)
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        let mut range = Range::new();
+
+        let mut start = 0;
+        loop {
+            let end = chars[start..]
+                .iter()
+                .position(|c| *c == ',')
+                .map_or(chars.len(), |offset| start + offset);
+            let (hands, weight) = parse_part(&chars, start, end)?;
+            for cards in hands {
+                range.insert(cards, weight);
+            }
+
+            if end == chars.len() {
+                return Ok(range);
+            }
+            start = end + 1;
+        }
+    }
+}
+
+/// A pair, or two ranks that are suited, offsuit or either when `suited` is None.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HandClass {
+    high: Rank,
+    low: Rank,
+    suited: Option<bool>,
+}
+
+impl HandClass {
+    fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    fn combos(&self) -> Vec<[Card; 2]> {
+        let mut combos = vec![];
+        for (i, first) in Suit::iter().enumerate() {
+            for (j, second) in Suit::iter().enumerate() {
+                let wanted = match self.suited {
+                    _ if self.is_pair() => i < j,
+                    Some(suited) => suited == (first == second),
+                    None => true,
+                };
+                if wanted {
+                    combos.push([Card::new(self.high, first), Card::new(self.low, second)]);
+                }
+            }
+        }
+        combos
+    }
+
+    /// Pairs from this one up to Aces, or the kicker going up to one under the top card.
+    fn and_up(self) -> Vec<HandClass> {
+        if self.is_pair() {
+            return ranks_between(self.high, Rank::Ace)
+                .map(|rank| HandClass {
+                    high: rank,
+                    low: rank,
+                    ..self
+                })
+                .collect();
+        }
+
+        ranks_between(self.low, self.high)
+            .filter(|low| *low != self.high)
+            .map(|low| HandClass { low, ..self })
+            .collect()
+    }
+
+    /// Every hand from one end of a run to the other, `position` being where the '-' is.
+    fn span(self, other: HandClass, position: usize) -> Result<Vec<HandClass>, ParseRangeError> {
+        if self.is_pair() != other.is_pair() || self.suited != other.suited {
+            return Err(ParseRangeError::InvalidSpan { position });
+        }
+
+        if self.is_pair() {
+            let (bottom, top) = (self.high.min(other.high), self.high.max(other.high));
+            return Ok(ranks_between(bottom, top)
+                .map(|rank| HandClass {
+                    high: rank,
+                    low: rank,
+                    ..self
+                })
+                .collect());
+        }
+
+        if self.high == other.high {
+            let (bottom, top) = (self.low.min(other.low), self.low.max(other.low));
+            return Ok(ranks_between(bottom, top)
+                .map(|low| HandClass { low, ..self })
+                .collect());
+        }
+
+        let gap = self.high.value() - self.low.value();
+        if gap != other.high.value() - other.low.value() {
+            return Err(ParseRangeError::InvalidSpan { position });
+        }
+        let bottom = if self.high < other.high { self } else { other };
+        let top = self.high.max(other.high);
+        Ok(ranks_between(bottom.high, top)
+            .map(|high| HandClass {
+                high,
+                low: Rank::from_value(high.value() - gap).expect("the low card is below the top"),
+                ..self
+            })
+            .collect())
+    }
+}
+
+fn ranks_between(bottom: Rank, top: Rank) -> impl Iterator<Item = Rank> {
+    (bottom.value()..=top.value()).filter_map(Rank::from_value)
+}
+
+/// Parses the part of the range between `start` and `end`, returning its hole cards and weight.
+fn parse_part(
+    chars: &[char],
+    start: usize,
+    end: usize,
+) -> Result<(Vec<[Card; 2]>, f64), ParseRangeError> {
+    let first = (start..end)
+        .find(|i| !chars[*i].is_whitespace())
+        .ok_or(ParseRangeError::MissingHand { position: start })?;
+    let last = (first..end)
+        .rev()
+        .find(|i| !chars[*i].is_whitespace())
+        .map_or(end, |i| i + 1);
+
+    let (hand_end, weight) = match (first..last).find(|i| chars[*i] == ':') {
+        Some(colon) => (colon, parse_weight(chars, colon + 1, last)?),
+        None => (last, 1.0),
+    };
+    if hand_end == first {
+        return Err(ParseRangeError::MissingHand { position: first });
+    }
+
+    let is_exact = hand_end - first >= 2 && parse_suit(chars[first + 1]).is_some();
+    if is_exact {
+        return Ok((vec![parse_exact(chars, first, hand_end)?], weight));
+    }
+
+    let (class, next) = parse_class(chars, first, hand_end)?;
+    let classes = match char_at(chars, next, hand_end) {
+        None => vec![class],
+        Some('+') => {
+            expect_end(chars, next + 1, hand_end)?;
+            class.and_up()
+        }
+        Some('-') => {
+            let (other, after) = parse_class(chars, next + 1, hand_end)?;
+            expect_end(chars, after, hand_end)?;
+            class.span(other, next)?
+        }
+        Some(found) => {
+            return Err(ParseRangeError::UnexpectedCharacter {
+                position: next,
+                found,
+            })
+        }
+    };
+
+    Ok((classes.iter().flat_map(HandClass::combos).collect(), weight))
+}
+
+/// Parses a hand like "AK", "AKs" or "TT", returning it and the position just after it.
+fn parse_class(
+    chars: &[char],
+    position: usize,
+    end: usize,
+) -> Result<(HandClass, usize), ParseRangeError> {
+    let first = rank_at(chars, position, end)?;
+    let second = rank_at(chars, position + 1, end)?;
+    let (high, low) = (first.max(second), first.min(second));
+
+    let suited = match char_at(chars, position + 2, end).map(|c| c.to_ascii_lowercase()) {
+        Some('s') => Some(true),
+        Some('o') => Some(false),
+        _ => None,
+    };
+    if high == low && suited.is_some() {
+        return Err(ParseRangeError::SuitedPair {
+            position: position + 2,
+        });
+    }
+
+    let next = if suited.is_some() {
+        position + 3
+    } else {
+        position + 2
+    };
+    Ok((HandClass { high, low, suited }, next))
+}
+
+/// Parses exact hole cards like "AsKs", which must be all of the hand.
+fn parse_exact(chars: &[char], position: usize, end: usize) -> Result<[Card; 2], ParseRangeError> {
+    let first = Card::new(
+        rank_at(chars, position, end)?,
+        suit_at(chars, position + 1, end)?,
+    );
+    let second = Card::new(
+        rank_at(chars, position + 2, end)?,
+        suit_at(chars, position + 3, end)?,
+    );
+    expect_end(chars, position + 4, end)?;
+
+    if first == second {
+        return Err(ParseRangeError::SameCard {
+            position: position + 2,
+            card: second,
+        });
+    }
+    Ok([first, second])
+}
+
+fn parse_weight(chars: &[char], position: usize, end: usize) -> Result<f64, ParseRangeError> {
+    let text: String = chars[position..end].iter().collect();
+    text.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|weight| is_valid_weight(*weight))
+        .ok_or(ParseRangeError::InvalidWeight { position })
+}
+
+/// More than 0 and at most 1, which also rules out NaN.
+fn is_valid_weight(weight: f64) -> bool {
+    weight > 0.0 && weight <= 1.0
+}
+
+/// The character at `position` if it's before `end`.
+fn char_at(chars: &[char], position: usize, end: usize) -> Option<char> {
+    if position < end {
+        Some(chars[position])
+    } else {
+        None
+    }
+}
+
+fn rank_at(chars: &[char], position: usize, end: usize) -> Result<Rank, ParseRangeError> {
+    let found = char_at(chars, position, end).ok_or(ParseRangeError::UnexpectedEnd { position })?;
+    parse_rank(found).ok_or(ParseRangeError::InvalidRank { position, found })
+}
+
+fn suit_at(chars: &[char], position: usize, end: usize) -> Result<Suit, ParseRangeError> {
+    let found = char_at(chars, position, end).ok_or(ParseRangeError::UnexpectedEnd { position })?;
+    parse_suit(found).ok_or(ParseRangeError::InvalidSuit { position, found })
+}
+
+/// Errors if the hand carries on past `position`.
+fn expect_end(chars: &[char], position: usize, end: usize) -> Result<(), ParseRangeError> {
+    match char_at(chars, position, end) {
+        Some(found) => Err(ParseRangeError::UnexpectedCharacter { position, found }),
+        None => Ok(()),
+    }
+}
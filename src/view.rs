@@ -0,0 +1,48 @@
+use crate::{Action, Card, Round, TablePositions};
+
+/// An action a seat took this hand and how many chips it put in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionRecord {
+    pub seat: usize,
+    pub round: Round,
+    pub action: Action,
+    pub amount: u32,
+}
+
+/// Everything a single seat is allowed to know about the hand: its own hole cards and whatever
+/// is public at the table. Nobody else's hole cards are ever in here, so it's safe to hand to an
+/// agent or send to a front end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerView {
+    pub seat: usize,
+    pub hole_cards: Vec<Card>,
+    pub board: Vec<Card>,
+    pub round: Round,
+    pub positions: TablePositions,
+    /// Chips in front of every seat, not counting what's already in the pot.
+    pub stacks: Vec<u32>,
+    /// How much every seat has put in this street.
+    pub bets: Vec<u32>,
+    pub folded: Vec<bool>,
+    pub to_call: u32,
+    /// Everything put in by every player this hand.
+    pub pot: u32,
+    /// Every action taken this hand, oldest first. Blinds aren't included.
+    pub history: Vec<ActionRecord>,
+}
+
+impl PlayerView {
+    pub fn stack(&self) -> u32 {
+        self.stacks[self.seat]
+    }
+
+    /// How much the seat has put in this street.
+    pub fn bet(&self) -> u32 {
+        self.bets[self.seat]
+    }
+
+    /// Number of seats that haven't folded.
+    pub fn players_in_hand(&self) -> usize {
+        self.folded.iter().filter(|folded| !**folded).count()
+    }
+}
@@ -0,0 +1,128 @@
+use rust_poker::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn view() -> PlayerView {
+    PlayerView {
+        seat: 0,
+        hole_cards: parse_cards("As Kd").unwrap(),
+        board: vec![],
+        round: Round::PreFlop,
+        positions: TablePositions {
+            button: 0,
+            small_blind: 1,
+            big_blind: 2,
+        },
+        stacks: vec![100, 99, 98],
+        bets: vec![0, 1, 2],
+        folded: vec![false; 3],
+        to_call: 2,
+        pot: 3,
+        history: vec![],
+    }
+}
+
+fn scripted(actions: Vec<Action>) -> Box<dyn PlayerAgent> {
+    Box::new(ScriptedAgent::new(actions))
+}
+
+/// Only ever tries to check, counting how often it's told no.
+struct StubbornAgent {
+    rejected: Rc<Cell<u32>>,
+}
+
+impl PlayerAgent for StubbornAgent {
+    fn act(&mut self, _view: &PlayerView, _legal_actions: &[Action]) -> Action {
+        Action::Check
+    }
+
+    fn rejected(&mut self, _action: Action, _error: &ActionError) {
+        self.rejected.set(self.rejected.get() + 1);
+    }
+}
+
+fn play(mut agents: Vec<Box<dyn PlayerAgent>>) -> Vec<u32> {
+    let mut engine = Engine::new(agents.len()).unwrap();
+    play_session(&mut engine, &mut agents).unwrap()
+}
+
+#[test]
+fn scripted_agent_plays_its_actions_in_order() {
+    let mut agent = ScriptedAgent::new(vec![Action::Call, Action::Raise(10)]);
+    let legal = [Action::Fold, Action::Call, Action::Raise(4), Action::AllIn];
+
+    assert!(!agent.has_left());
+    assert_eq!(agent.act(&view(), &legal), Action::Call);
+    assert_eq!(agent.remaining(), 1);
+    assert_eq!(agent.act(&view(), &legal), Action::Raise(10));
+    assert!(agent.has_left());
+}
+
+#[test]
+fn scripted_agent_checks_or_folds_once_the_script_runs_out() {
+    let mut agent = ScriptedAgent::new(vec![]);
+
+    assert_eq!(
+        agent.act(&view(), &[Action::Fold, Action::Check, Action::Bet(2)]),
+        Action::Check
+    );
+    assert_eq!(
+        agent.act(&view(), &[Action::Fold, Action::Call]),
+        Action::Fold
+    );
+}
+
+#[test]
+fn everyone_folds_to_the_big_blind() {
+    // button on seat 0, seat 1 posts the small blind and seat 2 the big blind. Seat 0 tries to
+    // check first, which isn't allowed facing the big blind, so it's asked again
+    let agents = vec![
+        scripted(vec![Action::Check, Action::Fold]),
+        scripted(vec![Action::Fold]),
+        scripted(vec![]),
+    ];
+
+    let stacks = play(agents);
+    assert_eq!(stacks, vec![100, 99, 101]);
+}
+
+#[test]
+fn session_ends_when_one_player_has_all_the_chips() {
+    let agents = (0..3).map(|_| scripted(vec![Action::AllIn; 50])).collect();
+
+    let stacks = play(agents);
+    assert_eq!(stacks.iter().sum::<u32>(), 3 * STARTING_STACK);
+    assert_eq!(stacks.iter().filter(|stack| **stack > 0).count(), 1);
+}
+
+#[test]
+fn session_needs_an_agent_for_every_seat() {
+    let mut engine = Engine::new(3).unwrap();
+    let mut agents = vec![scripted(vec![]), scripted(vec![])];
+
+    assert_eq!(
+        play_session(&mut engine, &mut agents),
+        Err(EngineError::WrongNumberOfAgents {
+            agents: 2,
+            seats: 3
+        })
+    );
+    assert_eq!(engine.hands_played(), 0);
+}
+
+#[test]
+fn seat_folds_for_an_agent_that_keeps_acting_illegally() {
+    // seat 0 is on the button heads up, so it posts the small blind and acts first, where
+    // checking isn't allowed
+    let rejected = Rc::new(Cell::new(0));
+    let agents: Vec<Box<dyn PlayerAgent>> = vec![
+        Box::new(StubbornAgent {
+            rejected: rejected.clone(),
+        }),
+        scripted(vec![]),
+    ];
+
+    let stacks = play(agents);
+    assert_eq!(stacks, vec![99, 101]);
+    assert_eq!(rejected.get(), 10);
+}
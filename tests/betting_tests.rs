@@ -0,0 +1,318 @@
+use rust_poker::*;
+
+#[test]
+fn parse_actions() {
+    assert_eq!("f".parse(), Ok(Action::Fold));
+    assert_eq!("check".parse(), Ok(Action::Check));
+    assert_eq!("c".parse(), Ok(Action::Call));
+    assert_eq!("b 10".parse(), Ok(Action::Bet(10)));
+    assert_eq!("Raise 40\n".parse(), Ok(Action::Raise(40)));
+    assert_eq!("a".parse(), Ok(Action::AllIn));
+    assert!("b".parse::<Action>().is_err());
+    assert!("b ten".parse::<Action>().is_err());
+    assert!("x".parse::<Action>().is_err());
+}
+
+#[test]
+fn check_and_bet_when_nothing_to_call() {
+    let round = BettingRound::new(3, 2);
+
+    assert_eq!(round.validate(0, Action::Check, 100), Ok(0));
+    assert_eq!(round.validate(0, Action::Bet(2), 100), Ok(2));
+    assert_eq!(
+        round.validate(0, Action::Call, 100),
+        Err(ActionError::NothingToCall)
+    );
+    assert_eq!(
+        round.validate(0, Action::Raise(10), 100),
+        Err(ActionError::NothingToRaise)
+    );
+    assert_eq!(
+        round.validate(0, Action::Bet(1), 100),
+        Err(ActionError::BelowMinimum { minimum: 2 })
+    );
+    assert_eq!(
+        round.validate(0, Action::Bet(101), 100),
+        Err(ActionError::NotEnoughChips { stack: 100 })
+    );
+    assert_eq!(
+        round.legal_actions(0, 100),
+        vec![Action::Fold, Action::Check, Action::Bet(2), Action::AllIn]
+    );
+}
+
+#[test]
+fn facing_a_bet() {
+    let mut round = BettingRound::new(3, 2);
+    round.apply(0, Action::Bet(10), 100).unwrap();
+
+    assert_eq!(round.to_call(1), 10);
+    assert_eq!(
+        round.validate(1, Action::Check, 100),
+        Err(ActionError::CannotCheck { to_call: 10 })
+    );
+    assert_eq!(
+        round.validate(1, Action::Bet(20), 100),
+        Err(ActionError::AlreadyBet)
+    );
+    assert_eq!(round.validate(1, Action::Call, 100), Ok(10));
+    assert_eq!(
+        round.validate(1, Action::Raise(19), 100),
+        Err(ActionError::BelowMinimum { minimum: 20 })
+    );
+    assert_eq!(round.validate(1, Action::Raise(20), 100), Ok(20));
+    assert_eq!(
+        round.legal_actions(1, 100),
+        vec![Action::Fold, Action::Call, Action::Raise(20), Action::AllIn]
+    );
+}
+
+#[test]
+fn short_stack_can_call_or_raise_all_in() {
+    let mut round = BettingRound::new(2, 2);
+    round.apply(0, Action::Bet(10), 100).unwrap();
+
+    // calling for less than the bet puts the player all in
+    assert_eq!(round.validate(1, Action::Call, 6), Ok(6));
+    // raising all in for less than a full raise is allowed
+    assert_eq!(round.validate(1, Action::Raise(15), 15), Ok(15));
+    assert_eq!(round.validate(1, Action::AllIn, 15), Ok(15));
+
+    round.apply(1, Action::Call, 6).unwrap();
+    assert!(round.is_all_in(1));
+    assert_eq!(
+        round.validate(1, Action::Fold, 0),
+        Err(ActionError::CannotAct)
+    );
+    assert!(round.is_complete());
+}
+
+#[test]
+fn round_continues_until_bets_are_matched() {
+    let mut round = BettingRound::new(3, 2);
+
+    round.apply(0, Action::Check, 100).unwrap();
+    round.apply(1, Action::Bet(10), 100).unwrap();
+    assert!(!round.is_complete());
+    round.apply(2, Action::Call, 100).unwrap();
+    assert!(!round.is_complete());
+    assert!(round.needs_to_act(0));
+    assert!(!round.needs_to_act(1));
+    round.apply(0, Action::Call, 100).unwrap();
+
+    assert!(round.is_complete());
+    assert_eq!(round.current_bet(), 10);
+}
+
+#[test]
+fn raise_reopens_action() {
+    let mut round = BettingRound::new(3, 2);
+
+    round.apply(0, Action::Bet(10), 100).unwrap();
+    round.apply(1, Action::Call, 100).unwrap();
+    round.apply(2, Action::Raise(30), 100).unwrap();
+
+    assert!(round.needs_to_act(0));
+    assert!(round.needs_to_act(1));
+    assert_eq!(round.min_raise(), 20);
+    assert_eq!(
+        round.validate(0, Action::Raise(49), 90),
+        Err(ActionError::BelowMinimum { minimum: 50 })
+    );
+    round.apply(0, Action::Raise(50), 90).unwrap();
+    round.apply(1, Action::Fold, 90).unwrap();
+    assert!(!round.is_complete());
+    round.apply(2, Action::Call, 70).unwrap();
+
+    assert!(round.is_complete());
+    assert!(round.has_folded(1));
+}
+
+#[test]
+fn short_all_in_does_not_reopen_raising() {
+    let mut round = BettingRound::new(3, 2);
+
+    round.apply(0, Action::Bet(20), 100).unwrap();
+    round.apply(1, Action::Call, 100).unwrap();
+    // all in for 5 more than the bet, less than the minimum raise of 20
+    round.apply(2, Action::AllIn, 25).unwrap();
+
+    assert_eq!(round.current_bet(), 25);
+    assert!(round.needs_to_act(0));
+    assert_eq!(round.validate(0, Action::Call, 80), Ok(5));
+    assert_eq!(
+        round.validate(0, Action::Raise(50), 80),
+        Err(ActionError::RaiseNotAllowed)
+    );
+    assert_eq!(
+        round.validate(0, Action::AllIn, 80),
+        Err(ActionError::RaiseNotAllowed)
+    );
+}
+
+#[test]
+fn everyone_else_folding_ends_the_round() {
+    let mut round = BettingRound::new(3, 2);
+
+    round.apply(0, Action::Bet(10), 100).unwrap();
+    round.apply(1, Action::Fold, 100).unwrap();
+    round.apply(2, Action::Fold, 100).unwrap();
+
+    assert!(round.is_complete());
+    assert_eq!(round.players_in_hand(), 1);
+}
+
+#[test]
+fn next_street_keeps_folds_and_all_ins() {
+    let mut round = BettingRound::new(3, 2);
+    round.apply(0, Action::AllIn, 10).unwrap();
+    round.apply(1, Action::Fold, 100).unwrap();
+    round.apply(2, Action::Call, 100).unwrap();
+
+    let next = round.next_street();
+
+    assert!(next.is_all_in(0));
+    assert!(next.has_folded(1));
+    assert_eq!(next.current_bet(), 0);
+    assert_eq!(next.bet(2), 0);
+    // the only player with chips left has nobody to bet against
+    assert!(next.is_complete());
+}
+
+#[test]
+fn blinds_leave_the_big_blind_an_option() {
+    let mut round = BettingRound::new(3, 2);
+    assert_eq!(round.post_blind(1, 1, 100), 1);
+    assert_eq!(round.post_blind(2, 2, 100), 2);
+
+    assert_eq!(round.current_bet(), 2);
+    assert_eq!(round.to_call(0), 2);
+    assert_eq!(round.to_call(1), 1);
+    assert!(!round.is_complete());
+
+    round.apply(0, Action::Call, 100).unwrap();
+    round.apply(1, Action::Call, 99).unwrap();
+    assert!(round.needs_to_act(2));
+    assert_eq!(
+        round.legal_actions(2, 98),
+        vec![Action::Fold, Action::Check, Action::Raise(4), Action::AllIn]
+    );
+    round.apply(2, Action::Check, 98).unwrap();
+    assert!(round.is_complete());
+}
+
+#[test]
+fn no_option_or_raise_when_nobody_else_can_act() {
+    let mut round = BettingRound::new(3, 2);
+    round.post_blind(1, 1, 100);
+    round.post_blind(2, 2, 100);
+    round.apply(0, Action::Fold, 100).unwrap();
+    round.apply(1, Action::AllIn, 99).unwrap();
+
+    // the big blind can only call the all in or fold
+    assert_eq!(round.next_to_act(1), Some(2));
+    assert_eq!(
+        round.legal_actions(2, 198),
+        vec![Action::Fold, Action::Call]
+    );
+    assert_eq!(
+        round.validate(2, Action::Raise(200), 198),
+        Err(ActionError::NoOpponentCanAct)
+    );
+
+    // with the all in for less than the blind there's nothing left to decide
+    let mut round = BettingRound::new(3, 2);
+    round.post_blind(1, 1, 100);
+    round.post_blind(2, 2, 100);
+    round.apply(0, Action::Fold, 100).unwrap();
+    round.apply(1, Action::Call, 1).unwrap();
+    assert!(round.is_all_in(1));
+    assert_eq!(round.next_to_act(1), None);
+}
+
+#[test]
+fn short_blind_is_all_in_but_the_full_blind_is_owed() {
+    let mut round = BettingRound::new(3, 2);
+    round.post_blind(1, 1, 100);
+    assert_eq!(round.post_blind(2, 2, 1), 1);
+
+    assert!(round.is_all_in(2));
+    assert_eq!(round.to_call(0), 2);
+}
+
+#[test]
+fn sitting_out_is_not_in_the_hand() {
+    let mut round = BettingRound::new(3, 2);
+    round.sit_out(0);
+
+    assert!(round.has_folded(0));
+    assert!(!round.needs_to_act(0));
+    assert_eq!(round.players_in_hand(), 2);
+}
+
+#[test]
+fn preflop_action_starts_left_of_the_big_blind() {
+    let mut round = BettingRound::new(4, 2);
+    round.post_blind(1, 1, 100);
+    round.post_blind(2, 2, 100);
+
+    assert_eq!(round.next_to_act(2), Some(3));
+    round.apply(3, Action::Call, 100).unwrap();
+    assert_eq!(round.next_to_act(3), Some(0));
+    round.apply(0, Action::Fold, 100).unwrap();
+    assert_eq!(round.next_to_act(0), Some(1));
+    round.apply(1, Action::Call, 99).unwrap();
+    assert_eq!(round.next_to_act(1), Some(2));
+    round.apply(2, Action::Check, 98).unwrap();
+    assert_eq!(round.next_to_act(2), None);
+}
+
+#[test]
+fn raise_reopens_the_action_round_the_table() {
+    let mut round = BettingRound::new(4, 2);
+    round.sit_out(2);
+
+    // starting left of the button on seat 0, the empty seat is skipped
+    assert_eq!(round.next_to_act(0), Some(1));
+    round.apply(1, Action::Bet(10), 100).unwrap();
+    assert_eq!(round.next_to_act(1), Some(3));
+    round.apply(3, Action::Call, 100).unwrap();
+    assert_eq!(round.next_to_act(3), Some(0));
+    round.apply(0, Action::Raise(30), 100).unwrap();
+
+    // everyone who called goes again, in order from the raiser
+    assert_eq!(round.next_to_act(0), Some(1));
+    round.apply(1, Action::Call, 90).unwrap();
+    assert_eq!(round.next_to_act(1), Some(3));
+    round.apply(3, Action::Fold, 90).unwrap();
+    assert_eq!(round.next_to_act(3), None);
+}
+
+#[test]
+fn all_in_players_are_skipped() {
+    let mut round = BettingRound::new(3, 2);
+    round.apply(1, Action::AllIn, 20).unwrap();
+    round.apply(2, Action::Call, 100).unwrap();
+
+    assert_eq!(round.next_to_act(2), Some(0));
+    round.apply(0, Action::Raise(60), 100).unwrap();
+    // seat 1 has no chips left to respond with
+    assert_eq!(round.next_to_act(0), Some(2));
+}
+
+#[test]
+fn heads_up_button_acts_first_preflop_and_last_after() {
+    let mut round = BettingRound::new(2, 2);
+    round.post_blind(0, 1, 100);
+    round.post_blind(1, 2, 100);
+
+    // seat 0 is the button and small blind, seat 1 the big blind
+    assert_eq!(round.next_to_act(1), Some(0));
+    round.apply(0, Action::Call, 99).unwrap();
+    assert_eq!(round.next_to_act(0), Some(1));
+    round.apply(1, Action::Check, 98).unwrap();
+    assert_eq!(round.next_to_act(1), None);
+
+    let flop = round.next_street();
+    assert_eq!(flop.next_to_act(0), Some(1));
+}
@@ -0,0 +1,168 @@
+use rust_poker::*;
+use strum::IntoEnumIterator;
+
+fn card(rank: Rank, suit: Suit) -> PackedCard {
+    PackedCard::new(rank, suit)
+}
+
+#[test]
+fn card_set_insert_remove_contains() {
+    let mut set = CardSet::new();
+    let ace_of_spades = card(Rank::Ace, Suit::Spades);
+
+    assert!(set.is_empty());
+    assert!(set.insert(ace_of_spades));
+    assert!(!set.insert(ace_of_spades));
+    assert!(set.contains(ace_of_spades));
+    assert_eq!(set.len(), 1);
+    assert!(set.remove(ace_of_spades));
+    assert!(!set.remove(ace_of_spades));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn card_set_operations() {
+    let a: CardSet = vec![
+        card(Rank::Two, Suit::Hearts),
+        card(Rank::Three, Suit::Hearts),
+    ]
+    .into_iter()
+    .collect();
+    let b: CardSet = vec![
+        card(Rank::Three, Suit::Hearts),
+        card(Rank::Four, Suit::Clubs),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!((a | b).len(), 3);
+    assert_eq!(a & b, CardSet::from(card(Rank::Three, Suit::Hearts)));
+    assert_eq!(a - b, CardSet::from(card(Rank::Two, Suit::Hearts)));
+    assert_eq!(a.union(b), a | b);
+    assert_eq!(a.intersection(b), a & b);
+    assert_eq!(a.difference(b), a - b);
+    assert_eq!((!a).len(), 50);
+    assert!(!a.is_disjoint(b));
+    assert!((a & b).is_subset(a));
+    assert_eq!(CardSet::full().len(), 52);
+    assert_eq!(CardSet::from_bits(u64::MAX), CardSet::full());
+}
+
+#[test]
+fn card_set_removes_known_cards_from_the_deck() {
+    let hole = vec![
+        Card {
+            suit: Suit::Spades,
+            card_type: CardType::Face {
+                face_character: FaceCharacter::Ace,
+            },
+        },
+        Card {
+            suit: Suit::Spades,
+            card_type: CardType::Face {
+                face_character: FaceCharacter::King,
+            },
+        },
+    ];
+    let known = CardSet::try_from(&hole).unwrap();
+
+    let deck = CardSet::full() - known;
+
+    assert_eq!(deck.len(), 50);
+    assert!(deck.is_disjoint(known));
+    assert_eq!(known.to_cards(), vec![hole[1].clone(), hole[0].clone()]);
+}
+
+#[test]
+fn card_set_rejects_invalid_cards() {
+    let cards = vec![Card {
+        suit: Suit::Spades,
+        card_type: CardType::Number { number: 1 },
+    }];
+
+    assert!(CardSet::try_from(&cards).is_err());
+}
+
+#[test]
+fn card_set_iterates_in_index_order() {
+    let set: CardSet = vec![
+        card(Rank::Ace, Suit::Clubs),
+        card(Rank::Two, Suit::Diamonds),
+        card(Rank::Seven, Suit::Hearts),
+    ]
+    .into_iter()
+    .collect();
+
+    let cards: Vec<PackedCard> = set.iter().collect();
+
+    assert_eq!(
+        cards,
+        vec![
+            card(Rank::Two, Suit::Diamonds),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Ace, Suit::Clubs)
+        ]
+    );
+}
+
+#[test]
+fn card_set_suit_and_rank_masks() {
+    let board: CardSet = vec![
+        card(Rank::Ace, Suit::Hearts),
+        card(Rank::Jack, Suit::Hearts),
+        card(Rank::Five, Suit::Hearts),
+        card(Rank::Five, Suit::Spades),
+        card(Rank::Two, Suit::Hearts),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(board.suit_mask(Suit::Hearts), 1 << 12 | 1 << 9 | 1 << 3 | 1);
+    assert_eq!(board.suit_mask(Suit::Clubs), 0);
+    assert_eq!(board.rank_mask(), 1 << 12 | 1 << 9 | 1 << 3 | 1);
+    assert_eq!(board.rank_count(Rank::Five), 2);
+    assert_eq!(CardSet::suit(Suit::Clubs).len(), 13);
+    assert_eq!(CardSet::rank(Rank::Ace).len(), 4);
+    assert_eq!(board.flush_suit(), None);
+
+    let flush = board | CardSet::from(card(Rank::Nine, Suit::Hearts));
+    assert_eq!(flush.flush_suit(), Some(Suit::Hearts));
+}
+
+#[test]
+fn card_set_can_be_evaluated() {
+    let straight: CardSet = Rank::iter()
+        .take(5)
+        .map(|rank| card(rank, Suit::Hearts))
+        .collect();
+    let with_extra = straight | CardSet::from(card(Rank::Ace, Suit::Spades));
+
+    let strength = evaluator::set_strength(with_extra);
+
+    assert_eq!(strength, evaluator::set_strength(straight));
+    let cards = with_extra.to_cards();
+    let hand: Vec<&Card> = cards.iter().collect();
+    assert_eq!(evaluator::rank_hand(&hand), HandRank::StraightFlush);
+}
+
+#[test]
+fn claim_rejects_cards_already_in_play() {
+    let mut used = CardSet::new();
+    let ace: Card = "Ah".parse().unwrap();
+
+    assert_eq!(used.claim(&ace), Ok(card(Rank::Ace, Suit::Hearts)));
+    assert_eq!(
+        used.claim(&ace),
+        Err(CardError::DuplicateCard { card: ace.clone() })
+    );
+
+    let one = Card {
+        suit: Suit::Hearts,
+        card_type: CardType::Number { number: 1 },
+    };
+    assert_eq!(
+        used.claim(&one),
+        Err(CardError::InvalidCard { card: one.clone() })
+    );
+    assert_eq!(used.len(), 1);
+}
@@ -0,0 +1,35 @@
+use rust_poker::*;
+
+#[test]
+fn chip_values() {
+    assert_eq!(Chip::One.value(), 1);
+    assert_eq!(Chip::Five.value(), 5);
+    assert_eq!(Chip::TwentyFive.value(), 25);
+    assert_eq!(Chip::Fifty.value(), 50);
+}
+
+#[test]
+fn chip_breakdown_uses_fewest_chips() {
+    assert_eq!(
+        Chip::breakdown(57),
+        vec![Chip::Fifty, Chip::Five, Chip::One, Chip::One]
+    );
+    assert_eq!(
+        Chip::breakdown(STARTING_STACK),
+        vec![Chip::Fifty, Chip::Fifty]
+    );
+    assert_eq!(
+        Chip::breakdown(81),
+        vec![Chip::Fifty, Chip::TwentyFive, Chip::Five, Chip::One]
+    );
+    assert!(Chip::breakdown(0).is_empty());
+}
+
+#[test]
+fn chip_breakdown_adds_back_up() {
+    for amount in 0..500 {
+        let total: u32 = Chip::breakdown(amount).iter().map(|c| c.value()).sum();
+
+        assert_eq!(total, amount);
+    }
+}
@@ -0,0 +1,13 @@
+use rust_poker::{Action, Engine};
+
+/// Checks or calls for every seat until the hand is over.
+pub fn play_out_passively(engine: &mut Engine) {
+    while let Some(seat) = engine.to_act() {
+        let action = if engine.legal_actions().contains(&Action::Check) {
+            Action::Check
+        } else {
+            Action::Call
+        };
+        engine.apply(seat, action).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rust_poker::*;
+
+mod common;
+
+fn card(notation: &str) -> Card {
+    notation.parse().unwrap()
+}
+
+#[test]
+fn new_deck_has_every_card_once() {
+    let deck = Deck::new();
+
+    assert_eq!(deck.remaining(), 52);
+    let set = CardSet::try_from(deck.cards()).unwrap();
+    assert_eq!(set, CardSet::full());
+}
+
+#[test]
+fn deals_from_the_top() {
+    let mut deck = Deck::new();
+
+    assert_eq!(deck.deal(), Ok(card("Ac")));
+    assert_eq!(deck.deal_many(2), Ok(vec![card("Kc"), card("Qc")]));
+    assert_eq!(deck.burn(), Ok(card("Jc")));
+    assert_eq!(deck.remaining(), 48);
+    assert!(!deck.contains(&card("Ac")));
+}
+
+#[test]
+fn dealing_an_empty_deck_is_an_error() {
+    let mut deck = Deck::new();
+    deck.deal_many(50).unwrap();
+
+    assert_eq!(
+        deck.deal_many(3),
+        Err(DeckExhausted {
+            wanted: 3,
+            remaining: 2
+        })
+    );
+    // nothing was taken
+    assert_eq!(deck.remaining(), 2);
+
+    deck.deal_many(2).unwrap();
+    assert!(deck.is_empty());
+    assert_eq!(
+        deck.deal(),
+        Err(DeckExhausted {
+            wanted: 1,
+            remaining: 0
+        })
+    );
+    assert!(deck.burn().is_err());
+}
+
+#[test]
+fn known_cards_can_be_removed() {
+    let mut deck = Deck::new();
+    let dead = parse_cards("As Kd 7h").unwrap();
+
+    assert_eq!(deck.remove(&dead), 3);
+    assert_eq!(deck.remaining(), 49);
+    assert!(!deck.contains(&card("Kd")));
+    // already gone
+    assert_eq!(deck.remove(&dead), 0);
+}
+
+#[test]
+fn card_set_follows_what_is_left() {
+    let mut deck = Deck::new();
+    let dealt = deck.deal().unwrap();
+    deck.remove(&[card("2h")]);
+
+    let left = deck.card_set();
+    assert_eq!(left.len(), 50);
+    assert_eq!(left, CardSet::try_from(deck.cards()).unwrap());
+    assert!(!deck.contains(&dealt));
+}
+
+#[test]
+fn shuffle_keeps_every_card() {
+    let mut deck = Deck::new();
+    deck.shuffle(&mut StdRng::seed_from_u64(9));
+
+    assert_ne!(deck, Deck::new());
+    let set = CardSet::try_from(deck.cards()).unwrap();
+    assert_eq!(set, CardSet::full());
+}
+
+#[test]
+fn engine_burns_before_each_round() {
+    let seed = 11;
+    let mut engine = Engine::new(3).unwrap();
+    engine.start_hand_with_seed(seed).unwrap();
+    common::play_out_passively(&mut engine);
+
+    // deal the same deck by hand, two cards each then burn and turn
+    let mut deck = Deck::new();
+    deck.shuffle(&mut StdRng::seed_from_u64(seed));
+    for seat in 0..3 {
+        assert_eq!(
+            deck.deal_many(2).unwrap(),
+            engine.view(seat).unwrap().hole_cards
+        );
+    }
+    let mut board = vec![];
+    for cards in [3, 1, 1] {
+        deck.burn().unwrap();
+        board.extend(deck.deal_many(cards).unwrap());
+    }
+    assert_eq!(engine.board(), board);
+}
+
+#[test]
+fn table_size_is_limited_by_the_deck() {
+    // a full table still has enough cards to play a hand to the river
+    let mut engine = Engine::new(MAX_PLAYERS).unwrap();
+    engine.start_hand().unwrap();
+    common::play_out_passively(&mut engine);
+    assert_eq!(engine.board().len(), 5);
+
+    assert!(matches!(
+        Engine::new(MAX_PLAYERS + 1),
+        Err(EngineError::TooManyPlayers { max: MAX_PLAYERS })
+    ));
+}
@@ -0,0 +1,219 @@
+use rust_poker::*;
+
+mod common;
+
+fn started(num_players: usize) -> Engine {
+    let mut engine = Engine::new(num_players).unwrap();
+    engine.start_hand().unwrap();
+    engine
+}
+
+#[test]
+fn start_hand_deals_and_posts_blinds() {
+    let engine = started(3);
+
+    assert!(engine.hand_in_progress());
+    assert_eq!(engine.hands_played(), 1);
+    assert_eq!(
+        engine.positions(),
+        Some(TablePositions {
+            button: 0,
+            small_blind: 1,
+            big_blind: 2
+        })
+    );
+    assert_eq!(engine.stacks(), vec![100, 99, 98]);
+    assert_eq!(engine.pot(), 3);
+    assert_eq!(engine.round(), Round::PreFlop);
+    assert!(engine.board().is_empty());
+    assert_eq!(engine.view(1).unwrap().hole_cards.len(), 2);
+
+    // left of the big blind is first
+    assert_eq!(engine.to_act(), Some(0));
+    assert_eq!(
+        engine.legal_actions(),
+        vec![Action::Fold, Action::Call, Action::Raise(4), Action::AllIn]
+    );
+}
+
+#[test]
+fn mistakes_are_errors() {
+    let mut engine = Engine::new(3).unwrap();
+    assert_eq!(
+        engine.apply(0, Action::Fold),
+        Err(EngineError::NoHandInProgress)
+    );
+    assert_eq!(engine.positions(), None);
+    assert_eq!(engine.view(0), Err(EngineError::NoHandInProgress));
+
+    engine.start_hand().unwrap();
+    assert_eq!(
+        engine.view(3),
+        Err(EngineError::NoSuchSeat { seat: 3, seats: 3 })
+    );
+    assert_eq!(engine.start_hand(), Err(EngineError::HandInProgress));
+    assert_eq!(
+        engine.apply(1, Action::Call),
+        Err(EngineError::NotYourTurn {
+            seat: 1,
+            expected: Some(0)
+        })
+    );
+    assert_eq!(
+        engine.apply(0, Action::Check),
+        Err(EngineError::IllegalAction(ActionError::CannotCheck {
+            to_call: 2
+        }))
+    );
+    // nothing changed
+    assert_eq!(engine.to_act(), Some(0));
+    assert_eq!(engine.stacks(), vec![100, 99, 98]);
+}
+
+#[test]
+fn need_two_players_with_chips() {
+    assert!(matches!(Engine::new(1), Err(EngineError::NotEnoughPlayers)));
+    assert!(matches!(
+        Engine::with_stacks(vec![100, 0, 0]),
+        Err(EngineError::NotEnoughPlayers)
+    ));
+}
+
+#[test]
+fn everyone_folds_to_the_big_blind() {
+    let mut engine = started(3);
+    engine.apply(0, Action::Fold).unwrap();
+    engine.apply(1, Action::Fold).unwrap();
+
+    assert!(!engine.hand_in_progress());
+    assert_eq!(engine.to_act(), None);
+    assert_eq!(engine.stacks(), vec![100, 99, 101]);
+
+    // hole cards are random, everything else is known
+    let events: Vec<GameEvent> = engine
+        .drain_events()
+        .into_iter()
+        .filter(|event| !matches!(event, GameEvent::HoleCardsDealt { .. }))
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            GameEvent::HandStarted {
+                hand: 1,
+                seed: engine.hand_seed().unwrap(),
+                positions: TablePositions {
+                    button: 0,
+                    small_blind: 1,
+                    big_blind: 2
+                }
+            },
+            GameEvent::BlindPosted {
+                seat: 1,
+                blind: Blind::Small,
+                amount: 1
+            },
+            GameEvent::BlindPosted {
+                seat: 2,
+                blind: Blind::Big,
+                amount: 2
+            },
+            GameEvent::PlayerActed {
+                seat: 0,
+                action: Action::Fold,
+                amount: 0
+            },
+            GameEvent::PlayerActed {
+                seat: 1,
+                action: Action::Fold,
+                amount: 0
+            },
+            GameEvent::PotAwarded(PotAward {
+                pot: Pot {
+                    amount: 3,
+                    eligible: vec![2]
+                },
+                winners: vec![(2, 3)]
+            }),
+            GameEvent::HandEnded {
+                stacks: vec![100, 99, 101]
+            },
+        ]
+    );
+    assert!(engine.drain_events().is_empty());
+}
+
+#[test]
+fn checked_down_hand_deals_the_whole_board() {
+    let mut engine = started(3);
+    common::play_out_passively(&mut engine);
+
+    assert!(!engine.hand_in_progress());
+    assert_eq!(engine.board().len(), 5);
+    assert_eq!(engine.stacks().iter().sum::<u32>(), 300);
+
+    let dealt: Vec<(Round, usize)> = engine
+        .drain_events()
+        .into_iter()
+        .filter_map(|event| match event {
+            GameEvent::BoardDealt { round, cards } => Some((round, cards.len())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        dealt,
+        vec![(Round::Flop, 3), (Round::Turn, 1), (Round::River, 1)]
+    );
+}
+
+#[test]
+fn postflop_action_starts_left_of_the_button() {
+    let mut engine = started(3);
+    engine.apply(0, Action::Call).unwrap();
+    engine.apply(1, Action::Call).unwrap();
+    engine.apply(2, Action::Check).unwrap();
+
+    assert_eq!(engine.round(), Round::Flop);
+    assert_eq!(engine.board().len(), 3);
+    assert_eq!(engine.to_act(), Some(1));
+}
+
+#[test]
+fn all_in_runs_out_the_board() {
+    let mut engine = started(3);
+    engine.apply(0, Action::AllIn).unwrap();
+    engine.apply(1, Action::AllIn).unwrap();
+    engine.apply(2, Action::AllIn).unwrap();
+
+    assert!(!engine.hand_in_progress());
+    assert_eq!(engine.board().len(), 5);
+    assert_eq!(engine.stacks().iter().sum::<u32>(), 300);
+}
+
+#[test]
+fn button_moves_each_hand() {
+    let mut engine = started(3);
+    engine.apply(0, Action::Fold).unwrap();
+    engine.apply(1, Action::Fold).unwrap();
+
+    engine.start_hand().unwrap();
+    assert_eq!(engine.hands_played(), 2);
+    assert_eq!(
+        engine.positions(),
+        Some(TablePositions {
+            button: 1,
+            small_blind: 2,
+            big_blind: 0
+        })
+    );
+    assert_eq!(engine.to_act(), Some(1));
+}
+
+#[test]
+fn engine_can_be_debug_printed_with_observers() {
+    let mut engine = started(2);
+    engine.add_observer(Box::new(ConsoleObserver::new()));
+
+    let printed = format!("{:?}", engine);
+    assert!(printed.starts_with("Engine {"));
+    assert!(printed.contains("observers: 1"));
+}
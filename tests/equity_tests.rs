@@ -0,0 +1,301 @@
+use rust_poker::equity::*;
+use rust_poker::*;
+
+fn cards(notation: &str) -> Vec<Card> {
+    parse_cards(notation).unwrap()
+}
+
+fn hands(notations: &[&str]) -> Vec<Vec<Card>> {
+    notations.iter().map(|n| cards(n)).collect()
+}
+
+#[test]
+fn aces_are_a_big_favourite_over_kings() {
+    let equity = monte_carlo(&hands(&["Ah Ad", "Kh Kd"]), &[], &[], 20_000, Some(7)).unwrap();
+
+    assert_eq!(equity.runouts, 20_000);
+    let aces = equity.hands[0];
+    let kings = equity.hands[1];
+    assert!(
+        (79.0..85.0).contains(&aces.equity_percent()),
+        "{}",
+        aces.equity_percent()
+    );
+    assert_eq!(aces.wins, kings.losses);
+    assert_eq!(aces.ties, kings.ties);
+    assert!((aces.equity_percent() + kings.equity_percent() - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn overcards_against_a_pair_is_close_to_a_coin_flip() {
+    let equity = monte_carlo(&hands(&["As Ks", "Qd Qc"]), &[], &[], 20_000, Some(3)).unwrap();
+
+    let overcards = equity.hands[0].equity_percent();
+    assert!((43.0..50.0).contains(&overcards), "{}", overcards);
+}
+
+#[test]
+fn same_seed_samples_the_same_boards() {
+    let hands = hands(&["Jh Th", "As 2d", "8c 8s"]);
+    let board = cards("9h 8h 2c");
+
+    let first = monte_carlo(&hands, &board, &[], 2_000, Some(42)).unwrap();
+    let second = monte_carlo(&hands, &board, &[], 2_000, Some(42)).unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn complete_board_is_scored_once() {
+    let equity = monte_carlo(
+        &hands(&["Ah Kh", "Qs Qd", "As Kd"]),
+        &cards("Ac Kc 7d 2s 3h"),
+        &[],
+        10_000,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(equity.runouts, 1);
+    assert_eq!(equity.hands[0].tie_percent(), 100.0);
+    assert_eq!(equity.hands[1].lose_percent(), 100.0);
+    assert_eq!(equity.hands[2].equity_percent(), 50.0);
+}
+
+#[test]
+fn dead_cards_cannot_come_on_the_board() {
+    // an ace or a king on the river is the only way past the set of sevens, and they're all dead
+    let equity = monte_carlo(
+        &hands(&["Ah Ad", "7h 7d"]),
+        &cards("Ks Kd 7c 2s"),
+        &cards("Ac As Kc Kh"),
+        2_000,
+        Some(1),
+    )
+    .unwrap();
+
+    assert_eq!(equity.hands[0].wins, 0);
+    assert_eq!(equity.hands[1].win_percent(), 100.0);
+}
+
+#[test]
+fn bad_input_is_rejected() {
+    assert_eq!(
+        monte_carlo(&hands(&["Ah Ad"]), &[], &[], 10, None),
+        Err(EquityError::TooFewHands)
+    );
+    assert_eq!(
+        monte_carlo(&hands(&["Ah Ad", "Kh"]), &[], &[], 10, None),
+        Err(EquityError::WrongNumberOfCards { hand: 1, count: 1 })
+    );
+    assert_eq!(
+        monte_carlo(
+            &hands(&["Ah Ad", "Kh Kd"]),
+            &cards("Ah 2c 3c"),
+            &[],
+            10,
+            None
+        ),
+        Err(EquityError::Card(CardError::DuplicateCard {
+            card: cards("Ah")[0].clone()
+        }))
+    );
+    assert_eq!(
+        monte_carlo(
+            &hands(&["Ah Ad", "Kh Kd"]),
+            &cards("2c 3c 4c 5c 6c 7c"),
+            &[],
+            10,
+            None
+        ),
+        Err(EquityError::BoardTooBig { count: 6 })
+    );
+}
+
+#[test]
+fn card_errors_read_the_same_everywhere() {
+    let error = EquityError::from(CardError::DuplicateCard {
+        card: cards("Ah")[0].clone(),
+    });
+
+    assert_eq!(error.to_string(), "Ah is used more than once");
+}
+
+#[test]
+fn error_messages_count_hands_from_one() {
+    let error = EquityError::WrongNumberOfCards { hand: 0, count: 3 };
+
+    assert_eq!(
+        error.to_string(),
+        "hand 1 has 3 cards, every hand needs exactly two"
+    );
+}
+
+#[test]
+fn exact_counts_every_river() {
+    let equity = exact(&hands(&["Ah Ad", "Kh Kd"]), &cards("2c 7s 9d Jc"), &[]).unwrap();
+
+    // only the two kings left in the deck save the kings
+    assert!(equity.exact);
+    assert_eq!(equity.runouts, 44);
+    assert_eq!(equity.hands[0].wins, 42);
+    assert_eq!(equity.hands[1].wins, 2);
+    assert_eq!(equity.hands[0].ties, 0);
+}
+
+#[test]
+fn exact_agrees_with_showdown_on_every_turn_and_river() {
+    let players = hands(&["Ah Kh", "7c 7d", "Qs Jc"]);
+    let flop = cards("Qh Jh 2c");
+    let equity = exact(&players, &flop, &[]).unwrap();
+
+    let mut deck = Deck::new();
+    deck.remove(&players.concat());
+    deck.remove(&flop);
+    let remaining = deck.cards();
+    let mut expected = vec![(0, 0, 0); players.len()];
+    for i in 0..remaining.len() {
+        for j in i + 1..remaining.len() {
+            let mut board = flop.clone();
+            board.push(remaining[i].clone());
+            board.push(remaining[j].clone());
+            let values: Vec<HandValue> = players
+                .iter()
+                .map(|hole| best_hand(hole, &board).value)
+                .collect();
+            let best = values.iter().max().unwrap();
+            let winners = values.iter().filter(|v| *v == best).count();
+            for (counts, value) in expected.iter_mut().zip(&values) {
+                if value < best {
+                    counts.2 += 1;
+                } else if winners == 1 {
+                    counts.0 += 1;
+                } else {
+                    counts.1 += 1;
+                }
+            }
+        }
+    }
+
+    assert_eq!(equity.runouts, 903);
+    for (hand, (wins, ties, losses)) in equity.hands.iter().zip(expected) {
+        assert_eq!((hand.wins, hand.ties, hand.losses), (wins, ties, losses));
+    }
+}
+
+#[test]
+fn calculate_enumerates_when_there_are_few_runouts() {
+    let players = hands(&["As Ks", "Qd Qc"]);
+    let flop = cards("2s 7s Th");
+
+    let small = calculate(&players, &flop, &[], 10_000, Some(9)).unwrap();
+    assert!(small.exact);
+    assert_eq!(small, exact(&players, &flop, &[]).unwrap());
+
+    let preflop = calculate(&players, &[], &[], 1_000, Some(9)).unwrap();
+    assert!(!preflop.exact);
+    assert_eq!(preflop.runouts, 1_000);
+}
+
+fn ranges(notations: &[&str]) -> Vec<Range> {
+    notations.iter().map(|n| n.parse().unwrap()).collect()
+}
+
+#[test]
+fn single_combo_ranges_match_fixed_hands() {
+    let equity = range_equity(&ranges(&["AhAd", "KhKd"]), &[], &[], 20_000, Some(7)).unwrap();
+
+    let aces = equity.ranges[0].equity_percent();
+    assert!((79.0..85.0).contains(&aces), "{}", aces);
+    assert_eq!(equity.hero_combos.len(), 1);
+    assert_eq!(equity.hero_combos[0].equity, equity.ranges[0]);
+}
+
+#[test]
+fn combos_using_the_board_or_each_others_cards_are_never_dealt() {
+    let board = cards("As 2c 7d");
+    let equity = range_equity(&ranges(&["AA", "AKs"]), &board, &[], 3_000, Some(5)).unwrap();
+
+    // the ace of spades is on the board, leaving three ways to hold aces
+    let shown: Vec<String> = equity
+        .hero_combos
+        .iter()
+        .map(|c| c.combo.to_string())
+        .collect();
+    assert_eq!(shown, vec!["AhAd", "AhAc", "AdAc"]);
+    assert_eq!(equity.runouts, 3_000);
+    for result in &equity.ranges {
+        assert_eq!(result.runouts(), 3_000);
+    }
+
+    // a set of aces can only lose to a runner runner flush or straight
+    assert!(equity.ranges[0].equity_percent() > 90.0);
+}
+
+#[test]
+fn hero_breakdown_adds_up_to_the_whole_range() {
+    let equity = range_equity(
+        &ranges(&["QQ+, AKs", "TT-88, AQs+"]),
+        &[],
+        &[],
+        5_000,
+        Some(11),
+    )
+    .unwrap();
+
+    assert_eq!(equity.hero_combos.len(), 22);
+    let mut total = HandEquity::default();
+    for combo in &equity.hero_combos {
+        total.wins += combo.equity.wins;
+        total.ties += combo.equity.ties;
+        total.losses += combo.equity.losses;
+    }
+    assert_eq!(total.wins, equity.ranges[0].wins);
+    assert_eq!(total.ties, equity.ranges[0].ties);
+    assert_eq!(total.losses, equity.ranges[0].losses);
+    assert_eq!(total.runouts(), 5_000);
+}
+
+#[test]
+fn combos_are_dealt_by_weight() {
+    let equity = range_equity(&ranges(&["AA:0.5, KK", "QQ"]), &[], &[], 9_000, Some(2)).unwrap();
+
+    let dealt = |rank: char| -> u64 {
+        equity
+            .hero_combos
+            .iter()
+            .filter(|c| c.combo.to_string().starts_with(rank))
+            .map(|c| c.equity.runouts())
+            .sum()
+    };
+    // three combos' worth of aces against six of kings
+    let share = dealt('A') as f64 / 9_000.0;
+    assert!((0.28..0.39).contains(&share), "{}", share);
+    assert_eq!(dealt('A') + dealt('K'), 9_000);
+}
+
+#[test]
+fn same_seed_deals_the_same_ranges() {
+    let players = ranges(&["22+", "AK, AQs"]);
+
+    let first = range_equity(&players, &cards("Jh 8c 3d"), &[], 1_000, Some(4)).unwrap();
+    let second = range_equity(&players, &cards("Jh 8c 3d"), &[], 1_000, Some(4)).unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn ranges_that_cannot_be_dealt_are_errors() {
+    assert_eq!(
+        range_equity(&ranges(&["AA", "AsKs"]), &cards("As 2c 7d"), &[], 10, None),
+        Err(EquityError::EmptyRange { range: 1 })
+    );
+    assert_eq!(
+        range_equity(&ranges(&["AsKs", "AsKs"]), &[], &[], 10, None),
+        Err(EquityError::RangesConflict)
+    );
+    assert_eq!(
+        range_equity(&ranges(&["AA"]), &[], &[], 10, None),
+        Err(EquityError::TooFewHands)
+    );
+}
@@ -0,0 +1,121 @@
+use itertools::Itertools;
+use rust_poker::evaluator;
+use rust_poker::*;
+use strum::IntoEnumIterator;
+
+fn deck() -> Vec<Card> {
+    let mut cards = vec![];
+    for suit in Suit::iter() {
+        for number in 2..11 {
+            cards.push(Card {
+                suit,
+                card_type: CardType::Number { number },
+            });
+        }
+        for face_character in FaceCharacter::iter() {
+            cards.push(Card {
+                suit,
+                card_type: CardType::Face { face_character },
+            });
+        }
+    }
+    cards
+}
+
+#[test]
+fn evaluator_agrees_with_rank_hand_on_every_hand() {
+    let deck = deck();
+    let encoded: Vec<u32> = deck.iter().map(evaluator::encode).collect();
+
+    let mut hands = 0;
+    let mut counts = std::collections::HashMap::new();
+    for a in 0..48 {
+        for b in a + 1..49 {
+            for c in b + 1..50 {
+                for d in c + 1..51 {
+                    for e in d + 1..52 {
+                        let hand = vec![&deck[a], &deck[b], &deck[c], &deck[d], &deck[e]];
+                        let strength = evaluator::evaluate([
+                            encoded[a], encoded[b], encoded[c], encoded[d], encoded[e],
+                        ]);
+
+                        let expected = hand_value(&hand);
+                        assert_eq!(
+                            evaluator::hand_value(&hand),
+                            expected,
+                            "{} {} {} {} {} (strength {})",
+                            hand[0],
+                            hand[1],
+                            hand[2],
+                            hand[3],
+                            hand[4],
+                            strength
+                        );
+                        *counts.entry(expected.rank).or_insert(0) += 1;
+                        hands += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    assert_eq!(hands, 2_598_960);
+    assert_eq!(counts[&HandRank::RoyalFlush], 4);
+    assert_eq!(counts[&HandRank::StraightFlush], 36);
+    assert_eq!(counts[&HandRank::FourOfAKind], 624);
+    assert_eq!(counts[&HandRank::FullHouse], 3_744);
+    assert_eq!(counts[&HandRank::Flush], 5_108);
+    assert_eq!(counts[&HandRank::Straight], 10_200);
+    assert_eq!(counts[&HandRank::ThreeOfAKind], 54_912);
+    assert_eq!(counts[&HandRank::TwoPair], 123_552);
+    assert_eq!(counts[&HandRank::Pair], 1_098_240);
+    assert_eq!(counts[&HandRank::HighCard], 1_302_540);
+}
+
+#[test]
+fn evaluator_strength_orders_like_hand_value() {
+    let deck = deck();
+    // 10 through Ace of hearts, then 2 through 6 of spades, then a pair of twos
+    let royal = vec![&deck[8], &deck[9], &deck[10], &deck[11], &deck[12]];
+    let six_high = vec![&deck[26], &deck[27], &deck[28], &deck[29], &deck[30]];
+    let twos = vec![&deck[0], &deck[13], &deck[27], &deck[28], &deck[29]];
+
+    assert_eq!(evaluator::hand_strength(&royal), evaluator::DISTINCT_HANDS);
+    assert!(evaluator::hand_strength(&six_high) > evaluator::hand_strength(&twos));
+    assert_eq!(evaluator::rank_hand(&twos), HandRank::Pair);
+}
+
+#[test]
+fn evaluator_scores_seven_cards_by_their_best_five() {
+    let deck = deck();
+    let hole = vec![deck[0].clone(), deck[13].clone()];
+    let board = vec![
+        deck[26].clone(),
+        deck[1].clone(),
+        deck[14].clone(),
+        deck[40].clone(),
+        deck[51].clone(),
+    ];
+    let hand: Vec<&Card> = hole.iter().chain(board.iter()).collect();
+
+    assert_eq!(evaluator::rank_hand(&hand), HandRank::FullHouse);
+    assert_eq!(evaluator::hand_value(&hand), best_hand(&hole, &board).value);
+}
+
+#[test]
+fn evaluator_best_strength_matches_every_five_card_subset() {
+    let deck: Vec<u32> = deck().iter().map(evaluator::encode).collect();
+    for start in 0..40 {
+        for len in 5..=7 {
+            // spread the cards over the suits so some boards have flushes and some don't
+            let cards: Vec<u32> = (0..len).map(|i| deck[(start + i * 9) % 52]).collect();
+            let expected = cards
+                .iter()
+                .combinations(5)
+                .map(|c| evaluator::evaluate([*c[0], *c[1], *c[2], *c[3], *c[4]]))
+                .max()
+                .unwrap();
+            assert_eq!(evaluator::best_strength(&cards), expected);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use rust_poker::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod common;
+
+/// Keeps a copy of every event it's told about.
+struct RecordingObserver {
+    events: Rc<RefCell<Vec<GameEvent>>>,
+}
+
+impl GameObserver for RecordingObserver {
+    fn observe(&mut self, event: &GameEvent) {
+        self.events.borrow_mut().push(event.clone());
+    }
+}
+
+fn engine_with_recorder(perspective: Perspective) -> (Engine, Rc<RefCell<Vec<GameEvent>>>) {
+    let events = Rc::new(RefCell::new(vec![]));
+    let mut engine = Engine::new(3).unwrap();
+    engine.add_observer_for(
+        perspective,
+        Box::new(RecordingObserver {
+            events: Rc::clone(&events),
+        }),
+    );
+    (engine, events)
+}
+
+fn hole_cards_seen(events: &[GameEvent]) -> Vec<(usize, Vec<Card>)> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::HoleCardsDealt { seat, cards } => Some((*seat, cards.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn omniscient_observers_see_every_event() {
+    let (mut engine, observed) = engine_with_recorder(Perspective::Omniscient);
+    engine.start_hand().unwrap();
+    common::play_out_passively(&mut engine);
+
+    assert_eq!(*observed.borrow(), engine.drain_events());
+}
+
+#[test]
+fn hole_cards_are_dealt_to_each_seat() {
+    let (mut engine, observed) = engine_with_recorder(Perspective::Omniscient);
+    engine.start_hand().unwrap();
+
+    let dealt = hole_cards_seen(&observed.borrow());
+    assert_eq!(dealt.len(), 3);
+    for (seat, cards) in dealt {
+        assert_eq!(cards, engine.view(seat).unwrap().hole_cards);
+    }
+}
+
+#[test]
+fn observers_only_see_their_own_hole_cards() {
+    let (mut engine, observed) = engine_with_recorder(Perspective::Seat(1));
+    engine.start_hand().unwrap();
+    assert_eq!(
+        hole_cards_seen(&observed.borrow()),
+        vec![(1, engine.view(1).unwrap().hole_cards)]
+    );
+
+    let (mut engine, observed) = engine_with_recorder(Perspective::Table);
+    engine.start_hand().unwrap();
+    common::play_out_passively(&mut engine);
+    assert!(hole_cards_seen(&observed.borrow()).is_empty());
+    // everything else is public
+    let public: Vec<GameEvent> = engine
+        .drain_events()
+        .into_iter()
+        .filter(|event| !matches!(event, GameEvent::HoleCardsDealt { .. }))
+        .collect();
+    assert_eq!(*observed.borrow(), public);
+}
+
+#[test]
+fn cards_are_only_shown_at_showdown() {
+    let (mut engine, observed) = engine_with_recorder(Perspective::Table);
+    engine.start_hand().unwrap();
+    engine.apply(0, Action::Fold).unwrap();
+    engine.apply(1, Action::Fold).unwrap();
+
+    assert!(!observed
+        .borrow()
+        .iter()
+        .any(|event| matches!(event, GameEvent::CardsShown { .. })));
+
+    observed.borrow_mut().clear();
+    engine.start_hand().unwrap();
+    common::play_out_passively(&mut engine);
+
+    let shown: Vec<usize> = observed
+        .borrow()
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::CardsShown { seat, .. } => Some(*seat),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(shown, vec![0, 1, 2]);
+    assert!(matches!(
+        observed.borrow().last(),
+        Some(GameEvent::HandEnded { .. })
+    ));
+}
+
+#[test]
+fn short_blind_posts_what_it_has() {
+    let events = Rc::new(RefCell::new(vec![]));
+    let mut engine = Engine::with_stacks(vec![100, 100, 1]).unwrap();
+    engine.add_observer(Box::new(RecordingObserver {
+        events: Rc::clone(&events),
+    }));
+    engine.start_hand().unwrap();
+
+    assert!(events.borrow().contains(&GameEvent::BlindPosted {
+        seat: 2,
+        blind: Blind::Big,
+        amount: 1
+    }));
+}
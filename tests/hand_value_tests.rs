@@ -0,0 +1,382 @@
+use rust_poker::*;
+
+fn number(number: u8, suit: Suit) -> Card {
+    Card {
+        suit,
+        card_type: CardType::Number { number },
+    }
+}
+
+fn face(face_character: FaceCharacter, suit: Suit) -> Card {
+    Card {
+        suit,
+        card_type: CardType::Face { face_character },
+    }
+}
+
+fn value(cards: &[Card]) -> HandValue {
+    let hand: Vec<&Card> = cards.iter().collect();
+    hand_value(&hand)
+}
+
+#[test]
+fn hand_value_pair_lists_pair_then_kickers() {
+    let hand = [
+        number(3, Suit::Spades),
+        face(FaceCharacter::King, Suit::Spades),
+        number(7, Suit::Hearts),
+        face(FaceCharacter::Ace, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Hearts),
+    ];
+
+    let expected = HandValue::new(
+        HandRank::Pair,
+        vec![Rank::King, Rank::Ace, Rank::Seven, Rank::Three],
+    );
+
+    assert_eq!(value(&hand), expected);
+}
+
+#[test]
+fn hand_value_rank_beats_kickers() {
+    let pair = [
+        number(2, Suit::Spades),
+        number(2, Suit::Hearts),
+        number(4, Suit::Clubs),
+        number(5, Suit::Clubs),
+        number(7, Suit::Diamonds),
+    ];
+    let high_card = [
+        face(FaceCharacter::Ace, Suit::Spades),
+        face(FaceCharacter::King, Suit::Hearts),
+        face(FaceCharacter::Queen, Suit::Clubs),
+        face(FaceCharacter::Jack, Suit::Clubs),
+        number(9, Suit::Diamonds),
+    ];
+
+    assert!(value(&pair) > value(&high_card));
+}
+
+#[test]
+fn hand_value_high_card_decided_by_last_kicker() {
+    let better = [
+        face(FaceCharacter::Ace, Suit::Spades),
+        face(FaceCharacter::Jack, Suit::Hearts),
+        number(9, Suit::Clubs),
+        number(6, Suit::Clubs),
+        number(4, Suit::Diamonds),
+    ];
+    let worse = [
+        face(FaceCharacter::Ace, Suit::Hearts),
+        face(FaceCharacter::Jack, Suit::Diamonds),
+        number(9, Suit::Spades),
+        number(6, Suit::Hearts),
+        number(3, Suit::Diamonds),
+    ];
+
+    assert!(value(&better) > value(&worse));
+}
+
+#[test]
+fn hand_value_pair_kicker() {
+    let ace_kicker = [
+        face(FaceCharacter::King, Suit::Spades),
+        face(FaceCharacter::King, Suit::Hearts),
+        face(FaceCharacter::Ace, Suit::Clubs),
+        number(7, Suit::Clubs),
+        number(3, Suit::Diamonds),
+    ];
+    let queen_kicker = [
+        face(FaceCharacter::King, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Diamonds),
+        face(FaceCharacter::Queen, Suit::Clubs),
+        number(7, Suit::Hearts),
+        number(3, Suit::Spades),
+    ];
+
+    assert!(value(&ace_kicker) > value(&queen_kicker));
+}
+
+#[test]
+fn hand_value_higher_pair_beats_better_kickers() {
+    let kings = [
+        face(FaceCharacter::King, Suit::Spades),
+        face(FaceCharacter::King, Suit::Hearts),
+        number(4, Suit::Clubs),
+        number(3, Suit::Clubs),
+        number(2, Suit::Diamonds),
+    ];
+    let queens = [
+        face(FaceCharacter::Queen, Suit::Clubs),
+        face(FaceCharacter::Queen, Suit::Diamonds),
+        face(FaceCharacter::Ace, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Clubs),
+        face(FaceCharacter::Jack, Suit::Spades),
+    ];
+
+    assert!(value(&kings) > value(&queens));
+}
+
+#[test]
+fn hand_value_two_pair() {
+    let aces_up = [
+        face(FaceCharacter::Ace, Suit::Spades),
+        face(FaceCharacter::Ace, Suit::Hearts),
+        number(2, Suit::Clubs),
+        number(2, Suit::Diamonds),
+        number(3, Suit::Diamonds),
+    ];
+    let kings_and_queens = [
+        face(FaceCharacter::King, Suit::Spades),
+        face(FaceCharacter::King, Suit::Hearts),
+        face(FaceCharacter::Queen, Suit::Clubs),
+        face(FaceCharacter::Queen, Suit::Diamonds),
+        face(FaceCharacter::Ace, Suit::Diamonds),
+    ];
+    let kings_and_jacks = [
+        face(FaceCharacter::King, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Diamonds),
+        face(FaceCharacter::Jack, Suit::Clubs),
+        face(FaceCharacter::Jack, Suit::Diamonds),
+        face(FaceCharacter::Ace, Suit::Hearts),
+    ];
+    let kings_and_jacks_ten_kicker = [
+        face(FaceCharacter::King, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Diamonds),
+        face(FaceCharacter::Jack, Suit::Clubs),
+        face(FaceCharacter::Jack, Suit::Diamonds),
+        number(10, Suit::Hearts),
+    ];
+
+    assert!(value(&aces_up) > value(&kings_and_queens));
+    assert!(value(&kings_and_queens) > value(&kings_and_jacks));
+    assert!(value(&kings_and_jacks) > value(&kings_and_jacks_ten_kicker));
+}
+
+#[test]
+fn hand_value_three_of_a_kind() {
+    let sevens_ace_kicker = [
+        number(7, Suit::Spades),
+        number(7, Suit::Hearts),
+        number(7, Suit::Clubs),
+        face(FaceCharacter::Ace, Suit::Diamonds),
+        number(2, Suit::Diamonds),
+    ];
+    let sevens_king_kicker = [
+        number(7, Suit::Spades),
+        number(7, Suit::Hearts),
+        number(7, Suit::Diamonds),
+        face(FaceCharacter::King, Suit::Diamonds),
+        face(FaceCharacter::Queen, Suit::Clubs),
+    ];
+    let eights = [
+        number(8, Suit::Spades),
+        number(8, Suit::Hearts),
+        number(8, Suit::Diamonds),
+        number(3, Suit::Diamonds),
+        number(2, Suit::Clubs),
+    ];
+
+    assert!(value(&sevens_ace_kicker) > value(&sevens_king_kicker));
+    assert!(value(&eights) > value(&sevens_ace_kicker));
+}
+
+#[test]
+fn hand_value_straight_decided_by_top_card() {
+    let ten_high = [
+        number(6, Suit::Spades),
+        number(7, Suit::Hearts),
+        number(8, Suit::Clubs),
+        number(9, Suit::Diamonds),
+        number(10, Suit::Diamonds),
+    ];
+    let nine_high = [
+        number(5, Suit::Spades),
+        number(6, Suit::Hearts),
+        number(7, Suit::Clubs),
+        number(8, Suit::Diamonds),
+        number(9, Suit::Diamonds),
+    ];
+
+    assert_eq!(value(&ten_high).rank, HandRank::Straight);
+    assert!(value(&ten_high) > value(&nine_high));
+}
+
+#[test]
+fn hand_value_flush_compares_every_card() {
+    let better = [
+        face(FaceCharacter::Ace, Suit::Hearts),
+        face(FaceCharacter::Queen, Suit::Hearts),
+        number(9, Suit::Hearts),
+        number(5, Suit::Hearts),
+        number(3, Suit::Hearts),
+    ];
+    let worse = [
+        face(FaceCharacter::Ace, Suit::Spades),
+        face(FaceCharacter::Queen, Suit::Spades),
+        number(9, Suit::Spades),
+        number(5, Suit::Spades),
+        number(2, Suit::Spades),
+    ];
+
+    assert_eq!(value(&better).rank, HandRank::Flush);
+    assert!(value(&better) > value(&worse));
+}
+
+#[test]
+fn hand_value_full_house() {
+    let threes_full_of_twos = [
+        number(3, Suit::Spades),
+        number(3, Suit::Hearts),
+        number(3, Suit::Clubs),
+        number(2, Suit::Diamonds),
+        number(2, Suit::Clubs),
+    ];
+    let twos_full_of_aces = [
+        number(2, Suit::Spades),
+        number(2, Suit::Hearts),
+        number(2, Suit::Diamonds),
+        face(FaceCharacter::Ace, Suit::Diamonds),
+        face(FaceCharacter::Ace, Suit::Clubs),
+    ];
+    let threes_full_of_fours = [
+        number(3, Suit::Spades),
+        number(3, Suit::Hearts),
+        number(3, Suit::Diamonds),
+        number(4, Suit::Diamonds),
+        number(4, Suit::Clubs),
+    ];
+
+    assert!(value(&threes_full_of_twos) > value(&twos_full_of_aces));
+    assert!(value(&threes_full_of_fours) > value(&threes_full_of_twos));
+}
+
+#[test]
+fn hand_value_four_of_a_kind_kicker() {
+    let nine_kicker = [
+        number(5, Suit::Spades),
+        number(5, Suit::Hearts),
+        number(5, Suit::Clubs),
+        number(5, Suit::Diamonds),
+        number(9, Suit::Clubs),
+    ];
+    let eight_kicker = [
+        number(5, Suit::Spades),
+        number(5, Suit::Hearts),
+        number(5, Suit::Clubs),
+        number(5, Suit::Diamonds),
+        number(8, Suit::Clubs),
+    ];
+
+    assert!(value(&nine_kicker) > value(&eight_kicker));
+}
+
+#[test]
+fn hand_value_straight_flush_decided_by_top_card() {
+    let nine_high = [
+        number(5, Suit::Clubs),
+        number(6, Suit::Clubs),
+        number(7, Suit::Clubs),
+        number(8, Suit::Clubs),
+        number(9, Suit::Clubs),
+    ];
+    let eight_high = [
+        number(4, Suit::Hearts),
+        number(5, Suit::Hearts),
+        number(6, Suit::Hearts),
+        number(7, Suit::Hearts),
+        number(8, Suit::Hearts),
+    ];
+
+    assert!(value(&nine_high) > value(&eight_high));
+}
+
+#[test]
+fn hand_value_same_cards_different_suits_tie() {
+    let hand1 = [
+        face(FaceCharacter::Jack, Suit::Spades),
+        face(FaceCharacter::Jack, Suit::Hearts),
+        number(9, Suit::Clubs),
+        number(4, Suit::Clubs),
+        number(2, Suit::Diamonds),
+    ];
+    let hand2 = [
+        number(2, Suit::Hearts),
+        number(4, Suit::Diamonds),
+        face(FaceCharacter::Jack, Suit::Clubs),
+        number(9, Suit::Spades),
+        face(FaceCharacter::Jack, Suit::Diamonds),
+    ];
+
+    assert_eq!(value(&hand1), value(&hand2));
+}
+
+#[test]
+fn hand_value_wheel_is_the_lowest_straight() {
+    let wheel = [
+        face(FaceCharacter::Ace, Suit::Spades),
+        number(2, Suit::Hearts),
+        number(3, Suit::Clubs),
+        number(4, Suit::Diamonds),
+        number(5, Suit::Diamonds),
+    ];
+    let six_high = [
+        number(2, Suit::Spades),
+        number(3, Suit::Hearts),
+        number(4, Suit::Clubs),
+        number(5, Suit::Clubs),
+        number(6, Suit::Diamonds),
+    ];
+    let aces = [
+        face(FaceCharacter::Ace, Suit::Spades),
+        face(FaceCharacter::Ace, Suit::Hearts),
+        face(FaceCharacter::Ace, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Clubs),
+        face(FaceCharacter::Queen, Suit::Diamonds),
+    ];
+
+    assert_eq!(
+        value(&wheel),
+        HandValue::new(HandRank::Straight, vec![Rank::Five])
+    );
+    assert!(value(&six_high) > value(&wheel));
+    assert!(value(&wheel) > value(&aces));
+}
+
+#[test]
+fn hand_value_wheel_is_the_lowest_straight_flush() {
+    let wheel = [
+        face(FaceCharacter::Ace, Suit::Clubs),
+        number(2, Suit::Clubs),
+        number(3, Suit::Clubs),
+        number(4, Suit::Clubs),
+        number(5, Suit::Clubs),
+    ];
+    let six_high = [
+        number(2, Suit::Hearts),
+        number(3, Suit::Hearts),
+        number(4, Suit::Hearts),
+        number(5, Suit::Hearts),
+        number(6, Suit::Hearts),
+    ];
+
+    assert_eq!(value(&wheel).rank, HandRank::StraightFlush);
+    assert!(value(&six_high) > value(&wheel));
+}
+
+#[test]
+fn try_hand_value_rejects_cards_not_in_a_deck() {
+    let hand = [
+        number(1, Suit::Spades),
+        number(2, Suit::Spades),
+        number(3, Suit::Spades),
+        number(4, Suit::Spades),
+        number(5, Suit::Spades),
+    ];
+    let refs: Vec<&Card> = hand.iter().collect();
+
+    assert_eq!(
+        try_hand_value(&refs),
+        Err("card numbers must be between 2 and 10")
+    );
+}
@@ -0,0 +1,120 @@
+use rust_poker::*;
+
+#[test]
+fn parse_single_cards() {
+    assert_eq!("As".parse(), Ok(Card::new(Rank::Ace, Suit::Spades)));
+    assert_eq!("Td".parse(), Ok(Card::new(Rank::Ten, Suit::Diamonds)));
+    assert_eq!("10d".parse(), Ok(Card::new(Rank::Ten, Suit::Diamonds)));
+    assert_eq!("2c".parse(), Ok(Card::new(Rank::Two, Suit::Clubs)));
+    assert_eq!(" kH ".parse(), Ok(Card::new(Rank::King, Suit::Hearts)));
+}
+
+#[test]
+fn parse_card_errors() {
+    assert_eq!("".parse::<Card>(), Err(ParseCardError::Empty));
+    assert_eq!(
+        "Xs".parse::<Card>(),
+        Err(ParseCardError::InvalidRank {
+            position: 0,
+            found: 'X'
+        })
+    );
+    assert_eq!(
+        "1s".parse::<Card>(),
+        Err(ParseCardError::InvalidRank {
+            position: 0,
+            found: '1'
+        })
+    );
+    assert_eq!(
+        "Ax".parse::<Card>(),
+        Err(ParseCardError::InvalidSuit {
+            position: 1,
+            found: 'x'
+        })
+    );
+    assert_eq!(
+        "A".parse::<Card>(),
+        Err(ParseCardError::MissingSuit { position: 1 })
+    );
+    assert_eq!(
+        "AsKd".parse::<Card>(),
+        Err(ParseCardError::UnexpectedCharacter {
+            position: 2,
+            found: 'K'
+        })
+    );
+}
+
+#[test]
+fn parse_cards_with_and_without_spaces() {
+    let expected = vec![
+        Card::new(Rank::Ace, Suit::Spades),
+        Card::new(Rank::King, Suit::Diamonds),
+        Card::new(Rank::Queen, Suit::Hearts),
+    ];
+
+    assert_eq!(parse_cards("AsKdQh"), Ok(expected.clone()));
+    assert_eq!(parse_cards("As Kd Qh"), Ok(expected.clone()));
+    assert_eq!(parse_cards("  As  KdQh "), Ok(expected));
+    assert_eq!(parse_cards(""), Ok(vec![]));
+}
+
+#[test]
+fn parse_cards_reports_positions() {
+    assert_eq!(
+        parse_cards("As Kd Qz"),
+        Err(ParseCardError::InvalidSuit {
+            position: 7,
+            found: 'z'
+        })
+    );
+    assert_eq!(
+        parse_cards("As Kd As"),
+        Err(ParseCardError::DuplicateCard {
+            position: 6,
+            card: Card::new(Rank::Ace, Suit::Spades)
+        })
+    );
+    assert_eq!(
+        parse_cards("As K"),
+        Err(ParseCardError::MissingSuit { position: 4 })
+    );
+    assert_eq!(
+        parse_cards("As Kd Qz").unwrap_err().to_string(),
+        "invalid suit 'z' at position 7, expected one of shdc"
+    );
+}
+
+#[test]
+fn parse_card_set() {
+    let set: CardSet = "AsKs".parse().unwrap();
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(PackedCard::new(Rank::King, Suit::Spades)));
+    assert_eq!(format!("{:#}", set), "Ks As");
+}
+
+#[test]
+fn compact_display_round_trips() {
+    let cards = parse_cards("2c 9h Td Js Ah").unwrap();
+
+    let compact: Vec<String> = cards.iter().map(|card| format!("{:#}", card)).collect();
+
+    assert_eq!(compact.join(" "), "2c 9h Td Js Ah");
+    assert_eq!(Card::new(Rank::Ace, Suit::Clubs).to_string(), "[Ace Clubs]");
+    assert_eq!(
+        format!("{:#}", PackedCard::new(Rank::Ten, Suit::Spades)),
+        "Ts"
+    );
+    assert_eq!("q".parse(), Ok(Rank::Queen));
+    assert_eq!("h".parse(), Ok(Suit::Hearts));
+}
+
+#[test]
+fn parsed_cards_can_be_ranked() {
+    let cards = parse_cards("Th Jh Qh Kh Ah").unwrap();
+    let hand: Vec<&Card> = cards.iter().collect();
+
+    assert_eq!(rank_hand(&hand), HandRank::RoyalFlush);
+}
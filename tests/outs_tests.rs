@@ -0,0 +1,128 @@
+use rust_poker::*;
+
+fn cards(notation: &str) -> Vec<Card> {
+    parse_cards(notation).unwrap()
+}
+
+fn analyse(hole: &str, board: &str) -> DrawAnalysis {
+    analyse_outs(&cards(hole), &cards(board)).unwrap()
+}
+
+fn outs_to(analysis: &DrawAnalysis, rank: HandRank) -> &Outs {
+    analysis.outs.iter().find(|outs| outs.rank == rank).unwrap()
+}
+
+#[test]
+fn flush_draw_has_nine_outs() {
+    let analysis = analyse("Ah 5h", "Kh 8h 2c");
+
+    assert_eq!(analysis.current, HandRank::HighCard);
+    assert_eq!(
+        analysis.draws,
+        vec![Draw::FlushDraw, Draw::BackdoorStraightDraw]
+    );
+
+    let flush = outs_to(&analysis, HandRank::Flush);
+    assert_eq!(flush.cards.len(), 9);
+    assert!(flush.cards.iter().all(|card| card.suit == Suit::Hearts));
+    // one minus the chance of two misses from 47 cards, 38 of them not hearts
+    let expected = 100.0 * (1.0 - (38.0 * 37.0) / (47.0 * 46.0));
+    assert!((flush.by_river_percent - expected).abs() < 1e-9);
+}
+
+#[test]
+fn straight_draws_count_the_ranks_that_complete_them() {
+    let open_ender = analyse("9s 8d", "7c 6h 2s");
+    assert_eq!(open_ender.draws, vec![Draw::OpenEnder]);
+    assert_eq!(outs_to(&open_ender, HandRank::Straight).cards.len(), 8);
+
+    let gutshot = analyse("9s 8d", "Jc 7h 2s");
+    assert_eq!(gutshot.draws, vec![Draw::Gutshot]);
+    assert_eq!(
+        outs_to(&gutshot, HandRank::Straight).cards,
+        cards("Th Td Ts Tc")
+    );
+}
+
+#[test]
+fn draws_only_the_board_has_are_not_the_players() {
+    // any Ten or Five gives everyone the same straight
+    let analysis = analyse("Ac Kd", "9h 8c 7s 6d");
+
+    assert!(analysis.draws.is_empty());
+    assert!(analysis
+        .outs
+        .iter()
+        .all(|outs| outs.rank != HandRank::Straight));
+    assert_eq!(outs_to(&analysis, HandRank::Pair).cards.len(), 6);
+}
+
+#[test]
+fn cards_that_only_improve_the_board_are_not_outs() {
+    // pairing the board or making trips of the board's pair does nothing for Ace King
+    let paired = analyse("Ah Kd", "Qc Qs 2h");
+    assert_eq!(paired.current, HandRank::Pair);
+    assert_eq!(paired.out_count(), 6);
+    assert_eq!(
+        outs_to(&paired, HandRank::TwoPair).cards,
+        cards("Kh Ks Kc Ad As Ac")
+    );
+
+    let unpaired = analyse("Ah Kd", "Qc 7s 2h");
+    assert_eq!(unpaired.out_count(), 6);
+    assert_eq!(outs_to(&unpaired, HandRank::Pair).cards.len(), 6);
+    assert!(
+        unpaired.improve_by_river_percent < 30.0,
+        "{}",
+        unpaired.improve_by_river_percent
+    );
+}
+
+#[test]
+fn a_flush_the_hole_cards_play_in_is_an_out() {
+    let analysis = analyse("Ah 3c", "Kh 8h 2h 9c");
+
+    assert_eq!(outs_to(&analysis, HandRank::Flush).cards.len(), 9);
+}
+
+#[test]
+fn on_the_turn_improving_is_just_hitting_an_out() {
+    let analysis = analyse("9s 8d", "7c 6h 2s Kd");
+
+    assert_eq!(
+        analysis.improve_by_river_percent,
+        100.0 * analysis.out_count() as f64 / 46.0
+    );
+    assert!(!analysis.draws.contains(&Draw::BackdoorFlushDraw));
+}
+
+#[test]
+fn made_hands_only_list_better_ranks() {
+    let analysis = analyse("7c 7d", "7h 2s 2c");
+
+    assert_eq!(analysis.current, HandRank::FullHouse);
+    assert!(analysis.draws.is_empty());
+    assert_eq!(outs_to(&analysis, HandRank::FourOfAKind).cards, cards("7s"));
+    assert!(analysis
+        .outs
+        .iter()
+        .all(|outs| outs.rank > HandRank::FullHouse));
+}
+
+#[test]
+fn only_the_flop_and_turn_can_be_analysed() {
+    assert_eq!(
+        analyse_outs(&cards("Ah Kh"), &cards("2c 3c 4c 5c 6c")),
+        Err(OutsError::WrongBoardSize { count: 5 })
+    );
+    assert_eq!(
+        analyse_outs(&cards("Ah"), &cards("2c 3c 4c")),
+        Err(OutsError::WrongNumberOfCards { count: 1 })
+    );
+    assert_eq!(
+        analyse_outs(&cards("Ah Kh"), &cards("Ah 3c 4c")),
+        Err(OutsError::Card(CardError::DuplicateCard {
+            card: "Ah".parse().unwrap()
+        }))
+    );
+}
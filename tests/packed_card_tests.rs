@@ -0,0 +1,72 @@
+use rust_poker::*;
+use std::collections::HashSet;
+use strum::IntoEnumIterator;
+
+#[test]
+fn packed_card_fits_in_a_byte() {
+    assert_eq!(std::mem::size_of::<PackedCard>(), 1);
+}
+
+#[test]
+fn packed_card_round_trips_every_card() {
+    let mut indexes = HashSet::new();
+    for suit in Suit::iter() {
+        let number_cards = (2..11).map(|number| CardType::Number { number });
+        let face_cards =
+            FaceCharacter::iter().map(|face_character| CardType::Face { face_character });
+        for card_type in number_cards.chain(face_cards) {
+            let card = Card { suit, card_type };
+
+            let packed = PackedCard::try_from(&card).unwrap();
+
+            assert_eq!(Card::from(packed), card);
+            assert_eq!(PackedCard::try_from(packed.index()), Ok(packed));
+            assert!(packed.index() < 52);
+            indexes.insert(packed.index());
+        }
+    }
+
+    assert_eq!(indexes.len(), 52);
+    assert_eq!(PackedCard::all().count(), 52);
+}
+
+#[test]
+fn packed_card_rejects_invalid_cards() {
+    let one = Card {
+        suit: Suit::Clubs,
+        card_type: CardType::Number { number: 1 },
+    };
+
+    assert!(PackedCard::try_from(&one).is_err());
+    assert!(PackedCard::try_from(52u8).is_err());
+}
+
+#[test]
+fn packed_card_orders_by_rank() {
+    let two_of_clubs = PackedCard::new(Rank::Two, Suit::Clubs);
+    let three_of_hearts = PackedCard::new(Rank::Three, Suit::Hearts);
+    let ace_of_hearts = PackedCard::try_from(&Card {
+        suit: Suit::Hearts,
+        card_type: CardType::Face {
+            face_character: FaceCharacter::Ace,
+        },
+    })
+    .unwrap();
+
+    assert!(two_of_clubs < three_of_hearts);
+    assert!(three_of_hearts < ace_of_hearts);
+    assert_eq!(ace_of_hearts.rank(), Rank::Ace);
+    assert_eq!(ace_of_hearts.rank_index(), 12);
+    assert_eq!(ace_of_hearts.suit(), Suit::Hearts);
+    assert_eq!(ace_of_hearts.to_string(), "[Ace Hearts]");
+}
+
+#[test]
+fn packed_card_bits_are_distinct() {
+    let all = PackedCard::all().fold(0u64, |bits, card| {
+        assert_eq!(bits & card.bit(), 0);
+        bits | card.bit()
+    });
+
+    assert_eq!(all.count_ones(), 52);
+}
@@ -0,0 +1,73 @@
+use rust_poker::*;
+
+fn positions(button: usize, small_blind: usize, big_blind: usize) -> TablePositions {
+    TablePositions {
+        button,
+        small_blind,
+        big_blind,
+    }
+}
+
+#[test]
+fn blinds_follow_the_button() {
+    let active = [true; 4];
+
+    let first = TablePositions::first_hand(0, &active).unwrap();
+    assert_eq!(first, positions(0, 1, 2));
+
+    let second = first.next_hand(&active).unwrap();
+    assert_eq!(second, positions(1, 2, 3));
+
+    // wraps round the table
+    let third = second.next_hand(&active).unwrap();
+    assert_eq!(third, positions(2, 3, 0));
+}
+
+#[test]
+fn heads_up_button_posts_the_small_blind() {
+    let active = [true, true];
+
+    let first = TablePositions::first_hand(0, &active).unwrap();
+    assert_eq!(first, positions(0, 0, 1));
+
+    let second = first.next_hand(&active).unwrap();
+    assert_eq!(second, positions(1, 1, 0));
+}
+
+#[test]
+fn dead_small_blind_when_the_big_blind_busts() {
+    let before = positions(0, 1, 2);
+
+    // seat 2 was the big blind and lost everything
+    let next = before.next_hand(&[true, true, false, true]).unwrap();
+    assert_eq!(next, positions(1, 2, 3));
+}
+
+#[test]
+fn dead_button_when_the_small_blind_busts() {
+    let before = positions(0, 1, 2);
+
+    // seat 1 was the small blind and lost everything, the button still moves onto its seat
+    let next = before.next_hand(&[true, false, true, true]).unwrap();
+    assert_eq!(next, positions(1, 2, 3));
+}
+
+#[test]
+fn down_to_heads_up() {
+    let before = positions(0, 1, 2);
+
+    let next = before.next_hand(&[true, false, true, false]).unwrap();
+    assert_eq!(next, positions(2, 2, 0));
+}
+
+#[test]
+fn no_hand_with_one_player_left() {
+    assert_eq!(TablePositions::first_hand(0, &[true, false]), None);
+    assert_eq!(positions(0, 0, 1).next_hand(&[false, true]), None);
+}
+
+#[test]
+fn first_button_skips_empty_seats() {
+    let first = TablePositions::first_hand(0, &[false, true, true, true]).unwrap();
+    assert_eq!(first, positions(1, 2, 3));
+}
@@ -0,0 +1,194 @@
+use rust_poker::*;
+
+fn pair(rank: Rank) -> Option<HandValue> {
+    Some(HandValue::new(HandRank::Pair, vec![rank]))
+}
+
+fn cards(notation: &str) -> Vec<Card> {
+    parse_cards(notation).unwrap()
+}
+
+/// Odd chips in plain seat order, seat 0 first.
+fn seat_order(num_seats: usize) -> Vec<usize> {
+    (0..num_seats).collect()
+}
+
+fn manager(contributions: &[u32]) -> PotManager {
+    let mut pots = PotManager::new(contributions.len());
+    for (seat, amount) in contributions.iter().enumerate() {
+        pots.contribute(seat, *amount);
+    }
+    pots
+}
+
+#[test]
+fn single_pot_when_nobody_is_short() {
+    let pots = manager(&[20, 20, 20]);
+
+    assert_eq!(pots.total(), 60);
+    assert_eq!(
+        pots.pots(),
+        vec![Pot {
+            amount: 60,
+            eligible: vec![0, 1, 2]
+        }]
+    );
+}
+
+#[test]
+fn three_way_all_in_builds_main_and_side_pot() {
+    // seat 0 is all in for 10, the other two carry on to 50
+    let pots = manager(&[10, 50, 50]);
+
+    assert_eq!(
+        pots.pots(),
+        vec![
+            Pot {
+                amount: 30,
+                eligible: vec![0, 1, 2]
+            },
+            Pot {
+                amount: 80,
+                eligible: vec![1, 2]
+            },
+        ]
+    );
+
+    // the short stack has the best hand but can only win the main pot
+    let hands = [pair(Rank::Ace), pair(Rank::King), pair(Rank::Queen)];
+    let awards = pots.award(&hands, &seat_order(hands.len()));
+    assert_eq!(awards[0].winners, vec![(0, 30)]);
+    assert_eq!(awards[1].winners, vec![(1, 80)]);
+    assert_eq!(pots.payouts(&awards), vec![30, 80, 0]);
+}
+
+#[test]
+fn four_way_all_in_orders_side_pots_by_stack() {
+    let pots = manager(&[25, 100, 50, 100]);
+
+    assert_eq!(
+        pots.pots(),
+        vec![
+            Pot {
+                amount: 100,
+                eligible: vec![0, 1, 2, 3]
+            },
+            Pot {
+                amount: 75,
+                eligible: vec![1, 2, 3]
+            },
+            Pot {
+                amount: 100,
+                eligible: vec![1, 3]
+            },
+        ]
+    );
+
+    let hands = [
+        pair(Rank::Two),
+        pair(Rank::Four),
+        pair(Rank::Ace),
+        pair(Rank::Three),
+    ];
+    let awards = pots.award(&hands, &seat_order(hands.len()));
+    assert_eq!(pots.payouts(&awards), vec![0, 100, 175, 0]);
+}
+
+#[test]
+fn folded_chips_stay_in_the_pot() {
+    let mut pots = manager(&[30, 10, 60, 60]);
+    pots.fold(0);
+
+    assert_eq!(
+        pots.pots(),
+        vec![
+            Pot {
+                amount: 40,
+                eligible: vec![1, 2, 3]
+            },
+            Pot {
+                amount: 120,
+                eligible: vec![2, 3]
+            },
+        ]
+    );
+
+    // the folded seat can't win even with the best hand
+    let hands = [pair(Rank::Ace), pair(Rank::Ten), pair(Rank::Nine), None];
+    let awards = pots.award(&hands, &seat_order(hands.len()));
+    assert_eq!(pots.payouts(&awards), vec![0, 40, 120, 0]);
+}
+
+#[test]
+fn uncalled_chips_go_back_to_the_bettor() {
+    let pots = manager(&[20, 100]);
+
+    let hands = [pair(Rank::Ace), pair(Rank::King)];
+    let awards = pots.award(&hands, &seat_order(hands.len()));
+    assert_eq!(awards.len(), 2);
+    assert_eq!(
+        awards[1].pot,
+        Pot {
+            amount: 80,
+            eligible: vec![1]
+        }
+    );
+    assert_eq!(pots.payouts(&awards), vec![40, 80]);
+}
+
+#[test]
+fn tied_hands_split_the_pot() {
+    let pots = manager(&[10, 10, 10]);
+
+    let hands = [pair(Rank::Ace), None, pair(Rank::Ace)];
+    let awards = pots.award(&hands, &seat_order(hands.len()));
+    assert_eq!(awards[0].winners, vec![(0, 15), (2, 15)]);
+}
+
+#[test]
+fn last_player_standing_wins_without_a_hand() {
+    let mut pots = manager(&[10, 10, 0]);
+    pots.fold(0);
+    pots.fold(1);
+
+    let awards = pots.award(&[None, None, None], &seat_order(3));
+    assert_eq!(pots.payouts(&awards), vec![0, 0, 20]);
+}
+
+#[test]
+fn odd_chips_go_left_of_the_button() {
+    let pots = manager(&[5, 5, 5, 5]);
+    let hands = [pair(Rank::Ace), pair(Rank::Ace), pair(Rank::Ace), None];
+
+    // button on seat 1, so seat 2 is first to get an odd chip, then seat 0 after wrapping
+    let order = OddChipRule::LeftOfButton.seat_order(1, &vec![vec![]; 4]);
+    assert_eq!(order, vec![2, 3, 0, 1]);
+    let awards = pots.award(&hands, &order);
+    assert_eq!(awards[0].winners, vec![(0, 7), (1, 6), (2, 7)]);
+
+    // a split with nothing left over ignores the order
+    let pots = manager(&[5, 5, 5, 5]);
+    let hands = [pair(Rank::Ace), None, pair(Rank::Ace), None];
+    let awards = pots.award(&hands, &order);
+    assert_eq!(awards[0].winners, vec![(0, 10), (2, 10)]);
+}
+
+#[test]
+fn odd_chips_go_to_the_highest_card_by_suit() {
+    let hole_cards = vec![
+        cards("Kh Qd"),
+        cards("Kd 2c"),
+        cards("Ks 3h"),
+        cards("Ac 4d"),
+    ];
+
+    let order = OddChipRule::HighestCardBySuit.seat_order(0, &hole_cards);
+    assert_eq!(order, vec![3, 2, 0, 1]);
+
+    let pots = manager(&[3, 3, 3, 2]);
+    let hands = [pair(Rank::Jack), pair(Rank::Jack), pair(Rank::Jack), None];
+    let awards = pots.award(&hands, &order);
+    // 8 in the main pot between three, the king of spades then the king of hearts get the extras
+    assert_eq!(awards[0].winners, vec![(0, 3), (1, 2), (2, 3)]);
+    assert_eq!(pots.payouts(&awards), vec![4, 3, 4, 0]);
+}
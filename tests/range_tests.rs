@@ -0,0 +1,173 @@
+use rust_poker::*;
+
+fn range(notation: &str) -> Range {
+    notation.parse().unwrap()
+}
+
+fn error(notation: &str) -> ParseRangeError {
+    notation.parse::<Range>().unwrap_err()
+}
+
+fn hole(notation: &str) -> [Card; 2] {
+    let cards = parse_cards(notation).unwrap();
+    [cards[0].clone(), cards[1].clone()]
+}
+
+fn shown(range: &Range) -> Vec<String> {
+    range.combos().iter().map(|c| c.to_string()).collect()
+}
+
+#[test]
+fn hands_count_their_combos() {
+    assert_eq!(range("AA").len(), 6);
+    assert_eq!(range("AKs").len(), 4);
+    assert_eq!(range("AKo").len(), 12);
+    assert_eq!(range("AK").len(), 16);
+    assert_eq!(range("AsKs").len(), 1);
+}
+
+#[test]
+fn plus_goes_up_to_aces_or_to_the_top_card() {
+    assert_eq!(range("TT+").len(), 5 * 6);
+    assert_eq!(range("ATs+").len(), 4 * 4);
+    assert!(range("ATs+").weight(&hole("Ah Kh")) == 1.0);
+    assert!(range("ATs+").weight(&hole("Ah 9h")) == 0.0);
+}
+
+#[test]
+fn runs_keep_the_top_card_or_step_both_down() {
+    let kickers = range("AQo-AJo");
+    assert_eq!(kickers.len(), 2 * 12);
+    assert_eq!(kickers.weight(&hole("Ac Jd")), 1.0);
+
+    let connectors = range("76s-54s");
+    assert_eq!(connectors.len(), 3 * 4);
+    assert_eq!(connectors.weight(&hole("6d 5d")), 1.0);
+    assert_eq!(connectors.weight(&hole("8d 7d")), 0.0);
+
+    assert_eq!(range("77-TT"), range("TT-77"));
+}
+
+#[test]
+fn parts_combine_and_count_overlaps_once() {
+    let parsed = range("TT+, AKs, AQo-AJo, KQs, 76s-54s");
+
+    assert_eq!(parsed.len(), 30 + 4 + 24 + 4 + 12);
+    assert_eq!(range("QQ+, KK").len(), 18);
+}
+
+#[test]
+fn weights_apply_to_the_part_and_later_parts_win() {
+    let parsed = range("AA:0.5, KK");
+    assert_eq!(parsed.weighted_len(), 3.0 + 6.0);
+    assert_eq!(parsed.weight(&hole("As Ah")), 0.5);
+
+    let overridden = range("QQ+, KK:0.25");
+    assert_eq!(overridden.weight(&hole("Kd Kc")), 0.25);
+    assert_eq!(overridden.weight(&hole("Qd Qc")), 1.0);
+}
+
+#[test]
+fn combos_show_higher_card_first() {
+    assert_eq!(shown(&range("KsAs")), vec!["AsKs"]);
+    assert_eq!(shown(&range("AKs:0.5"))[0], "AhKh:0.5");
+}
+
+#[test]
+fn malformed_ranges_say_where() {
+    assert_eq!(error(""), ParseRangeError::MissingHand { position: 0 });
+    assert_eq!(
+        error("AA,,KK"),
+        ParseRangeError::MissingHand { position: 3 }
+    );
+    assert_eq!(
+        error("AA, A"),
+        ParseRangeError::UnexpectedEnd { position: 5 }
+    );
+    assert_eq!(
+        error("AX"),
+        ParseRangeError::InvalidRank {
+            position: 1,
+            found: 'X'
+        }
+    );
+    assert_eq!(
+        error("AsKx"),
+        ParseRangeError::InvalidSuit {
+            position: 3,
+            found: 'x'
+        }
+    );
+    assert_eq!(
+        error("KK, AAs"),
+        ParseRangeError::SuitedPair { position: 6 }
+    );
+    assert_eq!(
+        error("AsAs"),
+        ParseRangeError::SameCard {
+            position: 2,
+            card: "As".parse().unwrap()
+        }
+    );
+    assert_eq!(
+        error("AKs-QJo"),
+        ParseRangeError::InvalidSpan { position: 3 }
+    );
+    assert_eq!(error("AK-T8"), ParseRangeError::InvalidSpan { position: 2 });
+    assert_eq!(
+        error("AA:1.5"),
+        ParseRangeError::InvalidWeight { position: 3 }
+    );
+    assert_eq!(
+        error("AA:x"),
+        ParseRangeError::InvalidWeight { position: 3 }
+    );
+    assert_eq!(
+        error("AKs+x"),
+        ParseRangeError::UnexpectedCharacter {
+            position: 4,
+            found: 'x'
+        }
+    );
+}
+
+#[test]
+fn error_messages_include_the_position() {
+    assert_eq!(
+        error("AA, QQ-KJ").to_string(),
+        "the hands either side of the '-' at position 6 don't make a run"
+    );
+}
+
+#[test]
+fn add_checks_the_cards_and_weight() {
+    let mut built = Range::new();
+    assert_eq!(built.add(hole("Kd Ac"), 0.5), Ok(()));
+    assert_eq!(built.add(hole("Ac Kd"), 1.0), Ok(()));
+    assert_eq!(shown(&built), vec!["AcKd"]);
+
+    let ace: Card = "Ac".parse().unwrap();
+    assert_eq!(
+        built.add([ace.clone(), ace.clone()], 1.0),
+        Err(RangeError::Card(CardError::DuplicateCard { card: ace }))
+    );
+    let one = Card {
+        suit: Suit::Clubs,
+        card_type: CardType::Number { number: 1 },
+    };
+    assert_eq!(
+        built.add([one.clone(), "Kd".parse().unwrap()], 1.0),
+        Err(RangeError::Card(CardError::InvalidCard {
+            card: one.clone()
+        }))
+    );
+    assert_eq!(built.weight(&[one, "Kd".parse().unwrap()]), 0.0);
+    for weight in [0.0, -0.5, 1.5, f64::INFINITY] {
+        assert_eq!(
+            built.add(hole("Qs Qh"), weight),
+            Err(RangeError::InvalidWeight { weight })
+        );
+    }
+    assert!(built.add(hole("Qs Qh"), f64::NAN).is_err());
+    assert_eq!(built.len(), 1);
+}
@@ -315,3 +315,63 @@ fn rank_hand_high_card() {
 
     assert_eq!(HandRank::HighCard, rank_hand(&hand));
 }
+
+#[test]
+fn rank_hand_wheel_straight() {
+    let hand = vec![
+        &Card {
+            suit: Suit::Spades,
+            card_type: CardType::Number { number: 2 },
+        },
+        &Card {
+            suit: Suit::Clubs,
+            card_type: CardType::Number { number: 3 },
+        },
+        &Card {
+            suit: Suit::Hearts,
+            card_type: CardType::Number { number: 4 },
+        },
+        &Card {
+            suit: Suit::Spades,
+            card_type: CardType::Number { number: 5 },
+        },
+        &Card {
+            suit: Suit::Diamonds,
+            card_type: CardType::Face {
+                face_character: FaceCharacter::Ace,
+            },
+        },
+    ];
+
+    assert_eq!(HandRank::Straight, rank_hand(&hand));
+}
+
+#[test]
+fn rank_hand_wheel_straight_flush() {
+    let hand = vec![
+        &Card {
+            suit: Suit::Hearts,
+            card_type: CardType::Face {
+                face_character: FaceCharacter::Ace,
+            },
+        },
+        &Card {
+            suit: Suit::Hearts,
+            card_type: CardType::Number { number: 2 },
+        },
+        &Card {
+            suit: Suit::Hearts,
+            card_type: CardType::Number { number: 3 },
+        },
+        &Card {
+            suit: Suit::Hearts,
+            card_type: CardType::Number { number: 4 },
+        },
+        &Card {
+            suit: Suit::Hearts,
+            card_type: CardType::Number { number: 5 },
+        },
+    ];
+
+    assert_eq!(HandRank::StraightFlush, rank_hand(&hand));
+}
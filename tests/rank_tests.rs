@@ -0,0 +1,72 @@
+use rust_poker::*;
+use strum::IntoEnumIterator;
+
+#[test]
+fn rank_values() {
+    assert_eq!(Rank::Two.value(), 2);
+    assert_eq!(Rank::Ten.value(), 10);
+    assert_eq!(Rank::Ace.value(), 14);
+    assert_eq!(Rank::Two.index(), 0);
+    assert_eq!(Rank::Ace.index(), 12);
+    assert_eq!(Rank::from_value(11), Some(Rank::Jack));
+    assert_eq!(Rank::from_value(1), None);
+    assert_eq!(Rank::from_value(15), None);
+    assert_eq!(Rank::from_index(12), Some(Rank::Ace));
+    assert_eq!(Rank::from_index(13), None);
+}
+
+#[test]
+fn rank_next_and_prev() {
+    assert_eq!(Rank::Ten.next(), Some(Rank::Jack));
+    assert_eq!(Rank::Ace.next(), None);
+    assert_eq!(Rank::Jack.prev(), Some(Rank::Ten));
+    assert_eq!(Rank::Two.prev(), None);
+}
+
+#[test]
+fn rank_iterates_lowest_to_highest() {
+    let ranks: Vec<Rank> = Rank::iter().collect();
+
+    assert_eq!(ranks.len(), 13);
+    assert_eq!(ranks[0], Rank::Two);
+    assert_eq!(ranks[12], Rank::Ace);
+    assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(ranks.windows(2).all(|pair| pair[0].next() == Some(pair[1])));
+}
+
+#[test]
+fn rank_converts_to_and_from_card_type() {
+    for rank in Rank::iter() {
+        let card_type = CardType::from(rank);
+
+        assert_eq!(Rank::try_from(&card_type), Ok(rank));
+    }
+
+    assert_eq!(
+        CardType::from(Rank::Queen),
+        CardType::Face {
+            face_character: FaceCharacter::Queen
+        }
+    );
+    assert_eq!(CardType::from(Rank::Nine), CardType::Number { number: 9 });
+    assert!(Rank::try_from(&CardType::Number { number: 1 }).is_err());
+    assert!(Rank::try_from(&CardType::Number { number: 200 }).is_err());
+}
+
+#[test]
+fn card_new_and_rank() {
+    let card = Card::new(Rank::King, Suit::Diamonds);
+
+    assert_eq!(
+        card,
+        Card {
+            suit: Suit::Diamonds,
+            card_type: CardType::Face {
+                face_character: FaceCharacter::King
+            },
+        }
+    );
+    assert_eq!(card.rank(), Rank::King);
+    assert_eq!(card.to_string(), "[King Diamonds]");
+    assert_eq!(Rank::Four.to_string(), "4");
+}
@@ -0,0 +1,84 @@
+use rand::rngs::mock::StepRng;
+use rust_poker::*;
+
+mod common;
+
+fn hole_cards(engine: &Engine) -> Vec<Vec<Card>> {
+    (0..engine.num_seats())
+        .map(|seat| engine.view(seat).unwrap().hole_cards)
+        .collect()
+}
+
+fn seeded(seed: u64) -> Engine {
+    let mut engine = Engine::new(4).unwrap();
+    engine.set_seed(seed);
+    engine
+}
+
+#[test]
+fn same_seed_deals_the_same_hands() {
+    let mut a = seeded(42);
+    let mut b = seeded(42);
+
+    for _ in 0..3 {
+        a.start_hand().unwrap();
+        b.start_hand().unwrap();
+        assert_eq!(a.hand_seed(), b.hand_seed());
+        assert_eq!(hole_cards(&a), hole_cards(&b));
+
+        common::play_out_passively(&mut a);
+        common::play_out_passively(&mut b);
+        assert_eq!(a.board(), b.board());
+        assert_eq!(a.stacks(), b.stacks());
+    }
+}
+
+#[test]
+fn different_seeds_deal_different_hands() {
+    let mut a = seeded(1);
+    let mut b = seeded(2);
+    a.start_hand().unwrap();
+    b.start_hand().unwrap();
+
+    assert_ne!(a.hand_seed(), b.hand_seed());
+    assert_ne!(hole_cards(&a), hole_cards(&b));
+}
+
+#[test]
+fn hand_can_be_replayed_from_its_seed() {
+    let mut engine = seeded(7);
+    engine.start_hand().unwrap();
+    common::play_out_passively(&mut engine);
+    engine.start_hand().unwrap();
+    let seed = engine.hand_seed().unwrap();
+    let cards = hole_cards(&engine);
+    common::play_out_passively(&mut engine);
+    let board = engine.board().to_vec();
+
+    let mut replay = Engine::new(4).unwrap();
+    replay.start_hand_with_seed(seed).unwrap();
+    assert_eq!(hole_cards(&replay), cards);
+    common::play_out_passively(&mut replay);
+    assert_eq!(replay.board(), board);
+}
+
+#[test]
+fn seed_is_sent_with_the_hand() {
+    let mut engine = seeded(3);
+    engine.start_hand().unwrap();
+
+    let seed = engine.hand_seed().unwrap();
+    assert!(matches!(
+        engine.drain_events().first(),
+        Some(GameEvent::HandStarted { seed: sent, .. }) if *sent == seed
+    ));
+}
+
+#[test]
+fn any_rng_can_be_used() {
+    let mut engine = Engine::new(2).unwrap();
+    engine.set_rng(StepRng::new(5, 0));
+    engine.start_hand().unwrap();
+
+    assert_eq!(engine.hand_seed(), Some(5));
+}
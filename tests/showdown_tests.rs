@@ -0,0 +1,167 @@
+use rust_poker::*;
+
+fn number(number: u8, suit: Suit) -> Card {
+    Card {
+        suit,
+        card_type: CardType::Number { number },
+    }
+}
+
+fn face(face_character: FaceCharacter, suit: Suit) -> Card {
+    Card {
+        suit,
+        card_type: CardType::Face { face_character },
+    }
+}
+
+#[test]
+fn best_hand_uses_both_hole_cards() {
+    let hole = vec![number(9, Suit::Hearts), number(9, Suit::Spades)];
+    let board = vec![
+        number(9, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Hearts),
+        face(FaceCharacter::King, Suit::Diamonds),
+        number(2, Suit::Clubs),
+        number(4, Suit::Spades),
+    ];
+
+    let best = best_hand(&hole, &board);
+
+    assert_eq!(best.value.rank, HandRank::FullHouse);
+    assert_eq!(best.cards.len(), 5);
+    assert!(best.cards.contains(&number(9, Suit::Hearts)));
+    assert!(best.cards.contains(&number(9, Suit::Spades)));
+    assert!(!best.cards.contains(&number(2, Suit::Clubs)));
+}
+
+#[test]
+fn best_hand_can_play_the_board() {
+    let hole = vec![number(2, Suit::Hearts), number(3, Suit::Spades)];
+    let board = vec![
+        number(10, Suit::Clubs),
+        face(FaceCharacter::Jack, Suit::Clubs),
+        face(FaceCharacter::Queen, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Clubs),
+        face(FaceCharacter::Ace, Suit::Clubs),
+    ];
+
+    let best = best_hand(&hole, &board);
+
+    assert_eq!(best.value.rank, HandRank::RoyalFlush);
+    assert_eq!(best.cards, board);
+}
+
+#[test]
+fn best_hand_picks_highest_kickers() {
+    let hole = vec![
+        face(FaceCharacter::Ace, Suit::Hearts),
+        number(3, Suit::Spades),
+    ];
+    let board = vec![
+        face(FaceCharacter::King, Suit::Clubs),
+        face(FaceCharacter::King, Suit::Diamonds),
+        number(8, Suit::Hearts),
+        number(6, Suit::Clubs),
+        number(2, Suit::Diamonds),
+    ];
+
+    let best = best_hand(&hole, &board);
+
+    assert_eq!(best.value.rank, HandRank::Pair);
+    assert_eq!(
+        best.value.kickers,
+        vec![Rank::King, Rank::Ace, Rank::Eight, Rank::Six,]
+    );
+}
+
+#[test]
+fn showdown_single_winner() {
+    let board = vec![
+        number(2, Suit::Clubs),
+        number(7, Suit::Diamonds),
+        face(FaceCharacter::Jack, Suit::Hearts),
+        face(FaceCharacter::Queen, Suit::Spades),
+        number(4, Suit::Clubs),
+    ];
+    let player_one = vec![
+        face(FaceCharacter::Ace, Suit::Hearts),
+        number(3, Suit::Spades),
+    ];
+    let player_two = vec![number(7, Suit::Hearts), number(8, Suit::Spades)];
+    let player_three = vec![
+        face(FaceCharacter::King, Suit::Hearts),
+        number(9, Suit::Spades),
+    ];
+
+    let winners = showdown(
+        &[Some(&player_one), Some(&player_two), Some(&player_three)],
+        &board,
+    );
+
+    assert_eq!(winners, vec![1]);
+}
+
+#[test]
+fn showdown_ignores_folded_players() {
+    let board = vec![
+        number(2, Suit::Clubs),
+        number(7, Suit::Diamonds),
+        face(FaceCharacter::Jack, Suit::Hearts),
+        face(FaceCharacter::Queen, Suit::Spades),
+        number(4, Suit::Clubs),
+    ];
+    let player_one = vec![
+        face(FaceCharacter::Ace, Suit::Hearts),
+        number(3, Suit::Spades),
+    ];
+    let player_two = vec![number(7, Suit::Hearts), number(7, Suit::Spades)];
+
+    let winners = showdown(&[Some(&player_one), None], &board);
+
+    assert_eq!(winners, vec![0]);
+    assert!(showdown(&[None, None], &board).is_empty());
+    assert_eq!(showdown(&[None, Some(&player_two)], &board), vec![1]);
+}
+
+#[test]
+fn showdown_returns_all_tied_players() {
+    let board = vec![
+        number(5, Suit::Clubs),
+        number(6, Suit::Diamonds),
+        number(7, Suit::Hearts),
+        number(8, Suit::Spades),
+        face(FaceCharacter::King, Suit::Clubs),
+    ];
+    let player_one = vec![number(9, Suit::Hearts), number(2, Suit::Spades)];
+    let player_two = vec![number(9, Suit::Clubs), number(3, Suit::Diamonds)];
+    let player_three = vec![
+        face(FaceCharacter::King, Suit::Hearts),
+        number(2, Suit::Clubs),
+    ];
+
+    let winners = showdown(
+        &[Some(&player_one), Some(&player_two), Some(&player_three)],
+        &board,
+    );
+
+    assert_eq!(winners, vec![0, 1]);
+}
+
+#[test]
+fn showdown_six_high_straight_beats_wheel() {
+    let board = vec![
+        number(2, Suit::Clubs),
+        number(3, Suit::Diamonds),
+        number(4, Suit::Hearts),
+        number(5, Suit::Spades),
+        face(FaceCharacter::King, Suit::Clubs),
+    ];
+    let wheel = vec![
+        face(FaceCharacter::Ace, Suit::Hearts),
+        number(9, Suit::Spades),
+    ];
+    let six_high = vec![number(6, Suit::Clubs), number(9, Suit::Diamonds)];
+
+    assert_eq!(best_hand(&wheel, &board).value.rank, HandRank::Straight);
+    assert_eq!(showdown(&[Some(&wheel), Some(&six_high)], &board), vec![1]);
+}
@@ -137,6 +137,7 @@ fn compare_number_and_face_card_type() {
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn sort_inner_vector() {
     let foo = vec![vec![23, 7, 5], vec![44, 6, 22], vec![123, 2, 1]];
     let expected = vec![vec![5, 7, 23], vec![6, 22, 44], vec![1, 2, 123]];
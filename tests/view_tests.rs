@@ -0,0 +1,118 @@
+use rust_poker::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Plays a script and keeps a copy of every view it was shown.
+struct RecordingAgent {
+    script: ScriptedAgent,
+    views: Rc<RefCell<Vec<PlayerView>>>,
+}
+
+impl PlayerAgent for RecordingAgent {
+    fn act(&mut self, view: &PlayerView, legal_actions: &[Action]) -> Action {
+        self.views.borrow_mut().push(view.clone());
+        self.script.act(view, legal_actions)
+    }
+
+    fn has_left(&self) -> bool {
+        self.script.has_left()
+    }
+}
+
+fn play(scripts: Vec<Vec<Action>>) -> Vec<PlayerView> {
+    let views = Rc::new(RefCell::new(vec![]));
+    let mut agents: Vec<Box<dyn PlayerAgent>> = scripts
+        .into_iter()
+        .map(|actions| {
+            Box::new(RecordingAgent {
+                script: ScriptedAgent::new(actions),
+                views: Rc::clone(&views),
+            }) as Box<dyn PlayerAgent>
+        })
+        .collect();
+
+    let mut engine = Engine::new(agents.len()).unwrap();
+    play_session(&mut engine, &mut agents).unwrap();
+    let views = views.borrow().clone();
+    views
+}
+
+#[test]
+fn view_shows_the_table_and_what_happened() {
+    let views = play(vec![
+        vec![Action::Call, Action::Call],
+        vec![Action::Call, Action::Fold],
+        vec![Action::Check, Action::Bet(4)],
+    ]);
+
+    // seat 2, the big blind, closing the preflop action
+    let view = &views[2];
+    assert_eq!(view.seat, 2);
+    assert_eq!(view.round, Round::PreFlop);
+    assert_eq!(
+        view.positions,
+        TablePositions {
+            button: 0,
+            small_blind: 1,
+            big_blind: 2
+        }
+    );
+    assert_eq!(view.stacks, vec![98, 98, 98]);
+    assert_eq!(view.stack(), 98);
+    assert_eq!(view.bets, vec![2, 2, 2]);
+    assert_eq!(view.to_call, 0);
+    assert_eq!(view.pot, 6);
+    assert!(view.board.is_empty());
+    assert_eq!(
+        view.history,
+        vec![
+            ActionRecord {
+                seat: 0,
+                round: Round::PreFlop,
+                action: Action::Call,
+                amount: 2
+            },
+            ActionRecord {
+                seat: 1,
+                round: Round::PreFlop,
+                action: Action::Call,
+                amount: 1
+            },
+        ]
+    );
+
+    // the first to act on the flop is left of the button, bets have been reset for the street
+    let view = &views[3];
+    assert_eq!(view.seat, 1);
+    assert_eq!(view.round, Round::Flop);
+    assert_eq!(view.board.len(), 3);
+    assert_eq!(view.bets, vec![0, 0, 0]);
+    assert_eq!(view.history.len(), 3);
+
+    // seat 1 folded on the flop and seat 2 bet into seat 0
+    let view = &views[5];
+    assert_eq!(view.seat, 0);
+    assert_eq!(view.folded, vec![false, true, false]);
+    assert_eq!(view.players_in_hand(), 2);
+    assert_eq!(view.to_call, 4);
+}
+
+#[test]
+fn view_only_has_the_seats_own_cards() {
+    let views = play(vec![
+        vec![Action::Call],
+        vec![Action::Call],
+        vec![Action::Check],
+    ]);
+
+    let hands: Vec<CardSet> = views
+        .iter()
+        .map(|view| CardSet::try_from(&view.hole_cards).unwrap())
+        .collect();
+    for hand in &hands {
+        assert_eq!(hand.len(), 2);
+    }
+    assert!(hands[0].is_disjoint(hands[1]));
+    assert!(hands[0].is_disjoint(hands[2]));
+    assert!(hands[1].is_disjoint(hands[2]));
+}